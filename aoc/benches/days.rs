@@ -0,0 +1,68 @@
+//! Benchmarks parsing and both parts of every day on its `input` file, or on a generated input
+//! if there is none.
+//!
+//! Select days with a filter, e.g. `cargo bench -p aoc --bench days -- day_05`.
+
+use aoc_core::Solver;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{path::Path, time::Duration};
+
+/// The workspace root all day directories are relative to.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The size of the inputs generated for days without an `input` file.
+const SIZE: usize = 100;
+
+fn bench_day<S: Solver>(c: &mut Criterion, day: u8, dir: &str, generate: fn(usize, u64) -> String) {
+    let dir = Path::new(ROOT).join(dir);
+    let input = std::fs::read_to_string(dir.join("input")).unwrap_or_else(|_| generate(SIZE, 0));
+    let mut parsed = S::parse_str(&input).expect("Valid puzzle input");
+    S::configure(&mut parsed, &dir).expect("Readable settings");
+    let mut group = c.benchmark_group(format!("day_{day:02}"));
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_millis(500));
+    group.bench_function("parse", |b| b.iter(|| S::parse_str(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+macro_rules! days {
+    ($($day:expr, $dir:expr, $krate:ident;)*) => {
+        fn days(c: &mut Criterion) {
+            $(bench_day::<$krate::Solution>(c, $day, $dir, $krate::generate);)*
+        }
+    };
+}
+
+days! {
+    1, "day_01/trebuchet", trebuchet;
+    2, "day_02/cube", cube;
+    3, "day_03/gear", gear;
+    4, "day_04/scratchcards", scratchcards;
+    5, "day_05/almanac", almanac;
+    6, "day_06/boats", boats;
+    7, "day_07/camel_cards", camel_cards;
+    8, "day_08/wasteland", wasteland;
+    9, "day_09/extrapolate", extrapolate;
+    10, "day_10/animal_loop", animal_loop;
+    11, "day_11/galaxy", galaxy;
+    12, "day_12/springs", springs;
+    13, "day_13/mirror", mirror;
+    14, "day_14/rocks", rocks;
+    15, "day_15/haskii", haskii;
+    16, "day_16/ray", ray;
+    17, "day_17/crucubles", crucubles;
+    18, "day_18/lagoon", lagoon;
+    19, "day_19/nulzrulez", nulzrulez;
+    20, "day_20/pulse", pulse;
+    21, "day_21/garden", garden;
+    22, "day_22/slabs", slabs;
+    23, "day_23/walk", walk;
+    24, "day_24/hailstorm", hailstorm;
+    25, "day_25/snowverload", snowverload;
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+//! Compares the alternative implementations some days carry on their `input` file, or on a
+//! generated input if there is none.
+
+use aoc_core::{
+    interval::{Interval, IntervalSet},
+    Solver,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::{path::Path, sync::Arc};
+
+/// The workspace root all day directories are relative to.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// The size of the inputs generated for days without an `input` file.
+const SIZE: usize = 100;
+
+fn read<S: Solver>(dir: &str, generate: fn(usize, u64) -> String) -> S::Input {
+    let input = std::fs::read_to_string(Path::new(ROOT).join(dir).join("input"))
+        .unwrap_or_else(|_| generate(SIZE, 0));
+    S::parse_str(&input).expect("Valid puzzle input")
+}
+
+/// Mapping every seed on its own compared to mapping ranges of length one.
+fn almanac(c: &mut Criterion) {
+    let parsed = read::<almanac::Solution>("day_05/almanac", almanac::generate);
+    let mut group = c.benchmark_group("almanac");
+    group.bench_function("process_states", |b| {
+        b.iter(|| {
+            let mut almanac = black_box(&parsed).clone();
+            almanac.process_states();
+            almanac.states
+        })
+    });
+    group.bench_function("range_process_state", |b| {
+        b.iter(|| {
+            let mut states: [IntervalSet<u64>; 8] = Default::default();
+            states[0] = parsed.states[0]
+                .iter()
+                .map(|s| Interval::new(*s, s + 1))
+                .collect();
+            let mut almanac = almanac::range::RangeAlmanac {
+                states,
+                translation: black_box(&parsed).translation.clone(),
+            };
+            almanac.process_state();
+            almanac.states
+        })
+    });
+    group.finish();
+}
+
+fn galaxy(c: &mut Criterion) {
+    let image = read::<galaxy::Solution>("day_11/galaxy", galaxy::generate);
+    let universe = galaxy::Universe::from_image(&image, 2);
+    let mut group = c.benchmark_group("galaxy");
+    group.sample_size(10);
+    group.bench_function("distance_sum_threads", |b| {
+        b.iter(|| black_box(&universe).calculate_distance_sum())
+    });
+    group.bench_function("distance_sum_single_thread", |b| {
+        b.iter(|| black_box(&universe).calculate_distance_sum_single_thread())
+    });
+    group.finish();
+}
+
+fn ray(c: &mut Criterion) {
+    let cave = read::<ray::Solution>("day_16/ray", ray::generate);
+    let mut group = c.benchmark_group("ray");
+    group.sample_size(10);
+    group.bench_function("start_rays", |b| {
+        b.iter(|| Arc::clone(black_box(&cave)).start_rays())
+    });
+    group.bench_function("start_rays_single", |b| {
+        b.iter(|| Arc::clone(black_box(&cave)).start_rays_single())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, almanac, galaxy, ray);
+criterion_main!(benches);
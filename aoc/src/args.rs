@@ -0,0 +1,351 @@
+use aoc_core::Source;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: aoc run (--day <DAY> [--part <PART>] [--input <PATH>] | --all) [--sample] [--format <FORMAT>]
+               [--jobs <N>] [--timeout <SECONDS>]
+       aoc bench (--day <DAY> [--part <PART>] [--input <PATH>] | --all) [--sample] [--runs <N>]
+                 [--jobs <N>] [--timeout <SECONDS>]
+       aoc verify [--day <DAY> [--part <PART>] | --all] [--record] [--jobs <N>] [--timeout <SECONDS>]
+       aoc generate --day <DAY> [--size <N>] [--seed <N>]
+       aoc render --day <DAY> [--input <PATH>] [--sample] [--format <FORMAT>] [--scale <N>]
+
+Commands:
+  run              Print the answers
+  bench            Print a table of the time spent parsing and solving each part
+  verify           Compare the answers for the `input` files to the ones accepted in
+                   `answers.toml`, running all days unless `--day` is given
+  generate         Print a synthetic puzzle input for the day
+  render           Draw the map of a day solved on one, highlighting how it is solved, like
+                   the path of `crucubles` or the tiles enclosed by the loop of `animal_loop`
+
+Options:
+  --day <DAY>      Run the solver of a single day (1-25)
+  --part <PART>    Only run the given part (1 or 2)
+  --input <PATH>   Read the puzzle input from PATH instead of the day's `input` file,
+                   `-` reads it from stdin
+  --sample         Use the day's `sample` file as puzzle input
+  --all            Run the solvers of all days
+  --runs <N>       Report the median time of N runs when benchmarking [default: 5]
+  --format <FORMAT>
+                   Print the answers as `text` or as `json` records holding the day, part,
+                   answer and the nanoseconds spent parsing and solving [default: text].
+                   Draw maps as plain `text`, as text colored for terminals with `ansi` or as
+                   a `ppm` or `png` image [default: ansi]
+  --jobs <N>       Run the days and the work they split up on N threads, timing one day at a
+                   time when benchmarking [default: the number of CPUs]
+  --timeout <SECONDS>
+                   Give up on days still running after SECONDS, reporting them as failed
+  --record         Accept the answers of parts that have no accepted answer yet when verifying
+  --size <N>       The size of the generated input, like the number of lines or the width of
+                   a map, which each day interprets in its own way [default: 100]
+  --seed <N>       The seed of the generated input, the same seed always giving the same
+                   input [default: 0]
+  --scale <N>      The pixels per tile of rendered images [default: 4]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    Verify,
+    Generate,
+    Render,
+}
+
+/// How `run` prints the answers and `render` draws the map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// One JSON object per line and answer
+    Json,
+    /// Text colored by ANSI escape codes
+    Ansi,
+    Ppm,
+    Png,
+}
+
+/// The days that should be run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Day(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    pub selection: Selection,
+    pub part: Option<u8>,
+    /// An input overriding the `input` file of the day
+    pub input: Option<Source>,
+    pub sample: bool,
+    /// How often each step is timed by `bench`
+    pub runs: u32,
+    pub format: Format,
+    /// Whether `verify` accepts the answers of parts without an accepted answer
+    pub record: bool,
+    /// The size of the input printed by `generate`
+    pub size: usize,
+    /// The seed of the input printed by `generate`
+    pub seed: u64,
+    /// The pixels per tile of the images drawn by `render`
+    pub scale: usize,
+    /// The number of threads of the shared pool, one per CPU if not given
+    pub jobs: Option<usize>,
+    /// How long a day may run before it is given up on
+    pub timeout: Option<Duration>,
+}
+
+impl Args {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        let command = match args.next().as_deref() {
+            Some("run") => Command::Run,
+            Some("bench") => Command::Bench,
+            Some("verify") => Command::Verify,
+            Some("generate") => Command::Generate,
+            Some("render") => Command::Render,
+            Some(c) => return Err(format!("Unknown command `{c}`")),
+            None => return Err("Missing command".into()),
+        };
+        let mut selection = None;
+        let mut part = None;
+        let mut input = None;
+        let mut sample = false;
+        let mut runs = None;
+        let mut format = None;
+        let mut record = false;
+        let mut size = None;
+        let mut seed = None;
+        let mut scale = None;
+        let mut jobs = None;
+        let mut timeout = None;
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("Missing value for `{arg}`"));
+            match arg.as_str() {
+                "--all" => selection = Some(Selection::All),
+                "--day" => {
+                    let v = value()?;
+                    match v.parse::<u8>() {
+                        Ok(d @ 1..=25) => selection = Some(Selection::Day(d)),
+                        _ => return Err(format!("Invalid day `{v}`")),
+                    }
+                }
+                "--part" => {
+                    let v = value()?;
+                    match v.parse::<u8>() {
+                        Ok(p @ 1..=2) => part = Some(p),
+                        _ => return Err(format!("Invalid part `{v}`")),
+                    }
+                }
+                "--input" => input = Some(Source::from_arg(Some(value()?))),
+                "--sample" => sample = true,
+                "--record" => record = true,
+                "--runs" => {
+                    let v = value()?;
+                    match v.parse::<u32>() {
+                        Ok(n @ 1..) => runs = Some(n),
+                        _ => return Err(format!("Invalid number of runs `{v}`")),
+                    }
+                }
+                "--format" => {
+                    let v = value()?;
+                    match v.as_str() {
+                        "text" => format = Some(Format::Text),
+                        "json" => format = Some(Format::Json),
+                        "ansi" => format = Some(Format::Ansi),
+                        "ppm" => format = Some(Format::Ppm),
+                        "png" => format = Some(Format::Png),
+                        _ => return Err(format!("Invalid format `{v}`")),
+                    }
+                }
+                "--size" => {
+                    let v = value()?;
+                    match v.parse::<usize>() {
+                        Ok(n) => size = Some(n),
+                        _ => return Err(format!("Invalid size `{v}`")),
+                    }
+                }
+                "--seed" => {
+                    let v = value()?;
+                    match v.parse::<u64>() {
+                        Ok(n) => seed = Some(n),
+                        _ => return Err(format!("Invalid seed `{v}`")),
+                    }
+                }
+                "--scale" => {
+                    let v = value()?;
+                    match v.parse::<usize>() {
+                        Ok(n @ 1..) => scale = Some(n),
+                        _ => return Err(format!("Invalid scale `{v}`")),
+                    }
+                }
+                "--jobs" => {
+                    let v = value()?;
+                    match v.parse::<usize>() {
+                        Ok(n @ 1..) => jobs = Some(n),
+                        _ => return Err(format!("Invalid number of jobs `{v}`")),
+                    }
+                }
+                "--timeout" => {
+                    let v = value()?;
+                    match v.parse::<u64>() {
+                        Ok(n @ 1..) => timeout = Some(Duration::from_secs(n)),
+                        _ => return Err(format!("Invalid timeout `{v}`")),
+                    }
+                }
+                _ => return Err(format!("Unknown argument `{arg}`")),
+            }
+        }
+        let selection = match (selection, command) {
+            (Some(selection), _) => selection,
+            (None, Command::Verify) => Selection::All,
+            (None, _) => return Err("Either `--day` or `--all` is required".into()),
+        };
+        if selection == Selection::All && input.is_some() {
+            return Err("`--input` can only be used with a single day".into());
+        }
+        if sample && input.is_some() {
+            return Err("`--input` and `--sample` can not be combined".into());
+        }
+        if command != Command::Bench && runs.is_some() {
+            return Err("`--runs` can only be used with `bench`".into());
+        }
+        match (command, format) {
+            (_, None) | (Command::Run, Some(Format::Text | Format::Json)) => {}
+            (Command::Run, Some(_)) => {
+                return Err("`run` prints the answers as `text` or `json`".into());
+            }
+            (Command::Render, Some(Format::Json)) => {
+                return Err("`render` draws maps as `text`, `ansi`, `ppm` or `png`".into());
+            }
+            (Command::Render, Some(_)) => {}
+            _ => return Err("`--format` can only be used with `run` and `render`".into()),
+        }
+        if command != Command::Verify && record {
+            return Err("`--record` can only be used with `verify`".into());
+        }
+        if command == Command::Verify && (sample || input.is_some()) {
+            return Err("`verify` only checks the `input` files".into());
+        }
+        if command != Command::Generate && (size.is_some() || seed.is_some()) {
+            return Err("`--size` and `--seed` can only be used with `generate`".into());
+        }
+        if command == Command::Generate
+            && (selection == Selection::All || part.is_some() || sample || input.is_some())
+        {
+            return Err("`generate` only takes a single `--day`, `--size` and `--seed`".into());
+        }
+        if command == Command::Render && (selection == Selection::All || part.is_some()) {
+            return Err("`render` draws the map of a single `--day`".into());
+        }
+        if command != Command::Render && scale.is_some() {
+            return Err("`--scale` can only be used with `render`".into());
+        }
+        if matches!(command, Command::Generate | Command::Render)
+            && (jobs.is_some() || timeout.is_some())
+        {
+            return Err(
+                "`--jobs` and `--timeout` can only be used with `run`, `bench` and `verify`".into(),
+            );
+        }
+        Ok(Self {
+            command,
+            selection,
+            part,
+            input,
+            sample,
+            runs: runs.unwrap_or(5),
+            format: format.unwrap_or(match command {
+                Command::Render => Format::Ansi,
+                _ => Format::Text,
+            }),
+            record,
+            size: size.unwrap_or(100),
+            seed: seed.unwrap_or(0),
+            scale: scale.unwrap_or(4),
+            jobs,
+            timeout,
+        })
+    }
+}
+
+#[test]
+fn test_parse_args() {
+    let args =
+        Args::parse(["run", "--day", "17", "--part", "2", "--input", "path"].map(String::from))
+            .unwrap();
+    assert_eq!(args.selection, Selection::Day(17));
+    assert_eq!(args.part, Some(2));
+    assert_eq!(args.input, Some(Source::Path("path".into())));
+    assert!(!args.sample);
+    let args = Args::parse(["run", "--day", "3", "--input", "-"].map(String::from)).unwrap();
+    assert_eq!(args.input, Some(Source::Stdin));
+    assert_eq!(args.command, Command::Run);
+    let args = Args::parse(["run", "--all", "--sample"].map(String::from)).unwrap();
+    assert_eq!(args.selection, Selection::All);
+    assert!(args.sample);
+    assert!(
+        Args::parse(["run", "--day", "3", "--sample", "--input", "p"].map(String::from)).is_err()
+    );
+    assert!(Args::parse(["run", "--day", "26"].map(String::from)).is_err());
+    assert!(Args::parse(["run"].map(String::from)).is_err());
+    let args = Args::parse(["bench", "--all", "--runs", "3"].map(String::from)).unwrap();
+    assert_eq!((args.command, args.runs), (Command::Bench, 3));
+    assert!(Args::parse(["run", "--all", "--runs", "3"].map(String::from)).is_err());
+    assert!(Args::parse(["bench", "--all", "--runs", "0"].map(String::from)).is_err());
+    let args = Args::parse(["run", "--all", "--format", "json"].map(String::from)).unwrap();
+    assert_eq!(args.format, Format::Json);
+    assert!(Args::parse(["run", "--all", "--format", "xml"].map(String::from)).is_err());
+    assert!(Args::parse(["bench", "--all", "--format", "json"].map(String::from)).is_err());
+    let args = Args::parse(["verify"].map(String::from)).unwrap();
+    assert_eq!(
+        (args.command, args.selection),
+        (Command::Verify, Selection::All)
+    );
+    let args = Args::parse(["verify", "--day", "5", "--record"].map(String::from)).unwrap();
+    assert_eq!((args.selection, args.record), (Selection::Day(5), true));
+    assert!(Args::parse(["verify", "--sample"].map(String::from)).is_err());
+    assert!(Args::parse(["run", "--all", "--record"].map(String::from)).is_err());
+    let args = Args::parse(["generate", "--day", "5", "--seed", "7"].map(String::from)).unwrap();
+    assert_eq!(
+        (args.command, args.size, args.seed),
+        (Command::Generate, 100, 7)
+    );
+    let args = Args::parse(["generate", "--day", "5", "--size", "20"].map(String::from)).unwrap();
+    assert_eq!((args.size, args.seed), (20, 0));
+    assert!(Args::parse(["generate", "--all"].map(String::from)).is_err());
+    assert!(Args::parse(["generate", "--day", "5", "--sample"].map(String::from)).is_err());
+    assert!(Args::parse(["generate", "--day", "5", "--seed", "-1"].map(String::from)).is_err());
+    assert!(Args::parse(["run", "--day", "5", "--size", "20"].map(String::from)).is_err());
+    let args = Args::parse(["render", "--day", "16", "--sample"].map(String::from)).unwrap();
+    assert_eq!(
+        (args.command, args.format, args.scale),
+        (Command::Render, Format::Ansi, 4)
+    );
+    let args =
+        Args::parse(["render", "--day", "10", "--format", "png", "--scale", "2"].map(String::from))
+            .unwrap();
+    assert_eq!((args.format, args.scale), (Format::Png, 2));
+    assert!(Args::parse(["render", "--all"].map(String::from)).is_err());
+    assert!(Args::parse(["render", "--day", "10", "--part", "1"].map(String::from)).is_err());
+    assert!(Args::parse(["render", "--day", "10", "--format", "json"].map(String::from)).is_err());
+    assert!(Args::parse(["render", "--day", "10", "--scale", "0"].map(String::from)).is_err());
+    assert!(Args::parse(["run", "--day", "10", "--format", "png"].map(String::from)).is_err());
+    assert!(Args::parse(["run", "--day", "10", "--scale", "2"].map(String::from)).is_err());
+    let args =
+        Args::parse(["run", "--all", "--jobs", "2", "--timeout", "30"].map(String::from)).unwrap();
+    assert_eq!(
+        (args.jobs, args.timeout),
+        (Some(2), Some(Duration::from_secs(30)))
+    );
+    let args = Args::parse(["verify", "--timeout", "5"].map(String::from)).unwrap();
+    assert_eq!(
+        (args.jobs, args.timeout),
+        (None, Some(Duration::from_secs(5)))
+    );
+    assert!(Args::parse(["run", "--all", "--jobs", "0"].map(String::from)).is_err());
+    assert!(Args::parse(["bench", "--all", "--timeout", "0"].map(String::from)).is_err());
+    assert!(Args::parse(["render", "--day", "16", "--jobs", "2"].map(String::from)).is_err());
+    assert!(Args::parse(["generate", "--day", "16", "--timeout", "2"].map(String::from)).is_err());
+}
@@ -0,0 +1,109 @@
+use aoc_core::Solver;
+use std::{
+    hint::black_box,
+    io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Times parsing the input and solving the selected part, or both parts if none is selected,
+/// with the settings in the given directory of the day.
+pub type Bench = fn(&str, &Path, Option<u8>, u32) -> io::Result<Timings>;
+
+/// The median time spent on each step of solving a day, `None` for parts that were skipped.
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+pub fn bench<S: Solver>(
+    input: &str,
+    dir: &Path,
+    part: Option<u8>,
+    runs: u32,
+) -> io::Result<Timings> {
+    let mut parsed = S::parse_str(input)?;
+    S::configure(&mut parsed, dir)?;
+    Ok(Timings {
+        parse: median(runs, || S::parse_str(input)),
+        part1: part
+            .is_none_or(|p| p == 1)
+            .then(|| median(runs, || S::part1(&parsed))),
+        part2: part
+            .is_none_or(|p| p == 2)
+            .then(|| median(runs, || S::part2(&parsed))),
+    })
+}
+
+/// Runs `f` the given number of times and returns the median time it took.
+fn median<R>(runs: u32, mut f: impl FnMut() -> R) -> Duration {
+    let mut times: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[times.len() / 2]
+}
+
+/// Prints one row per day and a final row with the sums of all columns.
+pub fn print_table(rows: &[(u8, &str, Timings)]) {
+    println!(
+        "{:>3}  {:<12} {:>10} {:>10} {:>10} {:>10}",
+        "Day", "Crate", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for (day, name, t) in rows {
+        println!(
+            "{day:>3}  {name:<12} {:>10} {:>10} {:>10} {:>10}",
+            format_duration(Some(t.parse)),
+            format_duration(t.part1),
+            format_duration(t.part2),
+            format_duration(Some(t.total())),
+        );
+    }
+    if rows.len() > 1 {
+        let sum = |f: fn(&Timings) -> Option<Duration>| {
+            rows.iter().filter_map(|(_, _, t)| f(t)).sum::<Duration>()
+        };
+        println!(
+            "{:>3}  {:<12} {:>10} {:>10} {:>10} {:>10}",
+            "",
+            "all",
+            format_duration(Some(sum(|t| Some(t.parse)))),
+            format_duration(Some(sum(|t| t.part1))),
+            format_duration(Some(sum(|t| t.part2))),
+            format_duration(Some(sum(|t| Some(t.total())))),
+        );
+    }
+}
+
+fn format_duration(d: Option<Duration>) -> String {
+    let Some(d) = d else {
+        return "-".into();
+    };
+    let nanos = d.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", d.as_secs_f64()),
+    }
+}
+
+#[test]
+fn test_format_duration() {
+    assert_eq!(format_duration(None), "-");
+    assert_eq!(format_duration(Some(Duration::from_nanos(999))), "999ns");
+    assert_eq!(format_duration(Some(Duration::from_micros(1500))), "1.5ms");
+    assert_eq!(format_duration(Some(Duration::from_millis(2500))), "2.50s");
+}
@@ -0,0 +1,162 @@
+use crate::bench::{bench, Bench};
+use aoc_core::{ParseError, Solver};
+use grid::Canvas;
+use std::{
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// The workspace root all day directories are relative to.
+const ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// Parses the input, applies the settings in the given directory of the day and solves the
+/// selected part, or both parts if none is selected.
+pub type Run = fn(&str, &Path, Option<u8>) -> io::Result<Report>;
+
+/// The answers of a single run and the time it took to find them.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub parse: Duration,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Answer {
+    pub part: u8,
+    pub answer: String,
+    pub solve: Duration,
+}
+
+/// Generates a synthetic puzzle input of the given size from a seed.
+pub type Generate = fn(usize, u64) -> String;
+
+/// Parses the input and draws the map of a day with the overlays showing how it is solved.
+pub type Render = fn(&str) -> Result<Canvas, ParseError>;
+
+/// A day of the calendar and the solver for its puzzle.
+pub struct Day {
+    pub day: u8,
+    /// The directory of the day relative to the workspace root
+    pub dir: &'static str,
+    pub run: Run,
+    pub bench: Bench,
+    pub generate: Generate,
+    /// Only days solved on a map can be rendered
+    pub render: Option<Render>,
+}
+
+impl Day {
+    /// The name of the crate solving the day.
+    pub fn name(&self) -> &'static str {
+        self.dir.rsplit('/').next().unwrap_or(self.dir)
+    }
+
+    /// The directory of the day holding its inputs and settings.
+    pub fn path(&self) -> PathBuf {
+        Path::new(ROOT).join(self.dir)
+    }
+}
+
+fn run<S: Solver>(input: &str, dir: &Path, part: Option<u8>) -> io::Result<Report> {
+    let start = Instant::now();
+    let mut input = S::parse_str(input)?;
+    let parse = start.elapsed();
+    S::configure(&mut input, dir)?;
+    let mut answers = Vec::new();
+    if part.is_none_or(|p| p == 1) {
+        let start = Instant::now();
+        let answer = S::part1(&input).to_string();
+        answers.push(Answer {
+            part: 1,
+            answer,
+            solve: start.elapsed(),
+        });
+    }
+    if part.is_none_or(|p| p == 2) {
+        let start = Instant::now();
+        let answer = S::part2(&input).to_string();
+        answers.push(Answer {
+            part: 2,
+            answer,
+            solve: start.elapsed(),
+        });
+    }
+    Ok(Report { parse, answers })
+}
+
+macro_rules! day {
+    ($day:expr, $dir:expr, $krate:ident) => {
+        day!($day, $dir, $krate, None)
+    };
+    ($day:expr, $dir:expr, $krate:ident, render) => {
+        day!(
+            $day,
+            $dir,
+            $krate,
+            Some(|input| {
+                let input = <$krate::Solution as Solver>::parse_str(input)?;
+                Ok($krate::render(&input))
+            })
+        )
+    };
+    ($day:expr, $dir:expr, $krate:ident, $render:expr) => {
+        Day {
+            day: $day,
+            dir: $dir,
+            run: run::<$krate::Solution>,
+            bench: bench::<$krate::Solution>,
+            generate: $krate::generate,
+            render: $render,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(1, "day_01/trebuchet", trebuchet),
+    day!(2, "day_02/cube", cube),
+    day!(3, "day_03/gear", gear),
+    day!(4, "day_04/scratchcards", scratchcards),
+    day!(5, "day_05/almanac", almanac),
+    day!(6, "day_06/boats", boats),
+    day!(7, "day_07/camel_cards", camel_cards),
+    day!(8, "day_08/wasteland", wasteland),
+    day!(9, "day_09/extrapolate", extrapolate),
+    day!(10, "day_10/animal_loop", animal_loop, render),
+    day!(11, "day_11/galaxy", galaxy),
+    day!(12, "day_12/springs", springs),
+    day!(13, "day_13/mirror", mirror),
+    day!(14, "day_14/rocks", rocks),
+    day!(15, "day_15/haskii", haskii),
+    day!(16, "day_16/ray", ray, render),
+    day!(17, "day_17/crucubles", crucubles, render),
+    day!(18, "day_18/lagoon", lagoon, render),
+    day!(19, "day_19/nulzrulez", nulzrulez),
+    day!(20, "day_20/pulse", pulse),
+    day!(21, "day_21/garden", garden, render),
+    day!(22, "day_22/slabs", slabs),
+    day!(23, "day_23/walk", walk, render),
+    day!(24, "day_24/hailstorm", hailstorm),
+    day!(25, "day_25/snowverload", snowverload),
+];
+
+#[test]
+fn test_generate() {
+    for day in DAYS {
+        let generate = day.generate;
+        assert_eq!(generate(10, 1), generate(10, 1), "Day {}", day.day);
+        assert_ne!(generate(10, 1), generate(10, 2), "Day {}", day.day);
+    }
+}
+
+#[test]
+fn test_generate_small() {
+    for day in DAYS {
+        for (size, seed) in [(0, 0), (1, 1), (2, 2), (2, 3)] {
+            let input = (day.generate)(size, seed);
+            if let Err(e) = (day.run)(&input, &day.path(), None) {
+                panic!("Day {} of size {size} from seed {seed}: {e}", day.day);
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::days::Answer;
+use std::{fmt::Write, time::Duration};
+
+/// A JSON object with the answer of a part and the time spent finding it.
+///
+/// Answers are strings since numbers beyond 2^53 lose precision in many JSON parsers.
+pub fn json(day: u8, parse: Duration, answer: &Answer) -> String {
+    format!(
+        r#"{{"day":{day},"part":{},"answer":"{}","parse_ns":{},"solve_ns":{}}}"#,
+        answer.part,
+        escape(&answer.answer),
+        parse.as_nanos(),
+        answer.solve.as_nanos()
+    )
+}
+
+/// Escapes the characters that can not appear in a JSON string as they are.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            c if c.is_control() => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[test]
+fn test_json() {
+    let answer = Answer {
+        part: 2,
+        answer: "say \"hi\"\n".into(),
+        solve: Duration::from_micros(3),
+    };
+    assert_eq!(
+        json(7, Duration::from_nanos(12), &answer),
+        r#"{"day":7,"part":2,"answer":"say \"hi\"\n","parse_ns":12,"solve_ns":3000}"#
+    );
+}
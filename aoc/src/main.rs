@@ -0,0 +1,200 @@
+mod args;
+mod bench;
+mod days;
+mod format;
+mod verify;
+
+use aoc_core::{pool, Answers, Source};
+use args::{Args, Command, Format, Selection};
+use days::{Day, DAYS};
+use grid::Canvas;
+use std::{
+    any::Any,
+    collections::VecDeque,
+    io::{self, Read, Write},
+    process::ExitCode,
+};
+
+fn main() -> ExitCode {
+    let args = match Args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{}", args::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let days: Vec<&Day> = match args.selection {
+        Selection::All => DAYS.iter().collect(),
+        Selection::Day(d) => match DAYS.iter().find(|day| day.day == d) {
+            Some(day) => vec![day],
+            None => {
+                eprintln!("There is no solver for day {d}");
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    if args.command == Command::Generate {
+        for day in days {
+            print!("{}", (day.generate)(args.size, args.seed));
+        }
+        return ExitCode::SUCCESS;
+    }
+    if let Some(day) = days
+        .iter()
+        .find(|day| args.command == Command::Render && day.render.is_none())
+    {
+        eprintln!(
+            "Day {} is not solved on a map that could be rendered",
+            day.day
+        );
+        return ExitCode::FAILURE;
+    }
+    let mut answers = match args.command {
+        Command::Verify => match Answers::load(Answers::PATH) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("{}: {e}", Answers::PATH);
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => Answers::new(),
+    };
+    if let Some(jobs) = args.jobs {
+        pool::init(jobs);
+    }
+    let mut code = ExitCode::SUCCESS;
+    let mut timings = Vec::new();
+    let mut summary = verify::Summary::default();
+    let mut days = days.into_iter().peekable();
+    let mut tasks = VecDeque::new();
+    loop {
+        // Days run concurrently, except for benchmarks that would slow each other down.
+        while days.peek().is_some() && (args.command != Command::Bench || tasks.is_empty()) {
+            let day: &'static Day = days.next().unwrap();
+            let file = if args.sample { "sample" } else { "input" };
+            let source = match &args.input {
+                Some(source) => source.clone(),
+                None => Source::Path(day.path().join(file)),
+            };
+            let task = pool::shared().spawn({
+                let (source, args) = (source.clone(), args.clone());
+                move || solve(day, &source, &args)
+            });
+            tasks.push_back((day, source, task));
+        }
+        let Some((day, source, task)) = tasks.pop_front() else {
+            break;
+        };
+        let outcome = match args.timeout {
+            Some(timeout) => task.try_join_timeout(timeout).unwrap_or_else(|| {
+                let message = format!("Timed out after {}s", timeout.as_secs());
+                Ok(Err(io::Error::new(io::ErrorKind::TimedOut, message)))
+            }),
+            None => task.try_join(),
+        };
+        // A day that panicked fails like one that timed out, the other days still run.
+        let outcome = outcome.unwrap_or_else(|panic| {
+            let message = format!("Panicked: {}", panic_message(&*panic));
+            Err(io::Error::other(message))
+        });
+        match outcome {
+            Ok(Outcome::Report(report)) if args.command == Command::Verify => {
+                summary.check(&mut answers, day, &report, args.record)
+            }
+            Ok(Outcome::Report(report)) => {
+                for answer in &report.answers {
+                    match args.format {
+                        Format::Json => println!("{}", format::json(day.day, report.parse, answer)),
+                        _ => println!(
+                            "Day {:>2} part {} ({}): {}",
+                            day.day,
+                            answer.part,
+                            day.name(),
+                            answer.answer
+                        ),
+                    }
+                }
+            }
+            Ok(Outcome::Timings(t)) => timings.push((day.day, day.name(), t)),
+            Ok(Outcome::Drawing(drawing)) => {
+                if let Err(e) = io::stdout().write_all(&drawing) {
+                    eprintln!("Day {:>2}: {e}", day.day);
+                    code = ExitCode::FAILURE;
+                }
+            }
+            Err(e) => {
+                eprintln!("Day {:>2}: {source}: {e}", day.day);
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    match args.command {
+        Command::Run | Command::Generate | Command::Render => {}
+        Command::Bench => bench::print_table(&timings),
+        Command::Verify => {
+            println!(
+                "{} correct, {} wrong, {} unknown, {} recorded",
+                summary.correct, summary.wrong, summary.unknown, summary.recorded
+            );
+            if summary.recorded > 0 {
+                if let Err(e) = answers.save(Answers::PATH) {
+                    eprintln!("{}: {e}", Answers::PATH);
+                    code = ExitCode::FAILURE;
+                }
+            }
+            if !summary.passed() {
+                code = ExitCode::FAILURE;
+            }
+        }
+    }
+    code
+}
+
+/// What solving a day produced for the command.
+enum Outcome {
+    Report(days::Report),
+    Timings(bench::Timings),
+    Drawing(Vec<u8>),
+}
+
+/// Reads the input of a day and runs the command on it.
+fn solve(day: &Day, source: &Source, args: &Args) -> io::Result<Outcome> {
+    let input = read(source)?;
+    Ok(match args.command {
+        Command::Run | Command::Verify => {
+            Outcome::Report((day.run)(&input, &day.path(), args.part)?)
+        }
+        Command::Bench => Outcome::Timings((day.bench)(&input, &day.path(), args.part, args.runs)?),
+        Command::Render => {
+            let render = day.render.expect("Only days that can be rendered");
+            Outcome::Drawing(draw(&render(&input)?, args.format, args.scale))
+        }
+        Command::Generate => unreachable!("Inputs are generated without reading any"),
+    })
+}
+
+/// Reads the whole input up front so reading it is not part of the timings.
+fn read(source: &Source) -> io::Result<String> {
+    let mut input = String::new();
+    source.open()?.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+fn draw(canvas: &Canvas, format: Format, scale: usize) -> Vec<u8> {
+    match format {
+        Format::Ansi => canvas.ansi().into_bytes(),
+        Format::Ppm => canvas.ppm(scale),
+        Format::Png => canvas.png(scale),
+        Format::Text | Format::Json => canvas.text().into_bytes(),
+    }
+}
+
+/// The message a panic was started with.
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    match panic.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => panic
+            .downcast_ref::<String>()
+            .map_or("Unknown cause", String::as_str),
+    }
+}
@@ -0,0 +1,82 @@
+use crate::days::{Day, Report};
+use aoc_core::Answers;
+
+/// Counts how the answers found by `verify` compare to the accepted ones.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub correct: usize,
+    pub wrong: usize,
+    /// Answers of parts without an accepted answer
+    pub unknown: usize,
+    /// Unknown answers that were accepted by `--record`
+    pub recorded: usize,
+}
+
+impl Summary {
+    /// Compares the answers of a day to the accepted ones and prints the result of each part.
+    ///
+    /// With `record` the answers of parts without an accepted answer are accepted.
+    pub fn check(&mut self, answers: &mut Answers, day: &Day, report: &Report, record: bool) {
+        for answer in &report.answers {
+            let result = match answers.get(day.day, answer.part) {
+                Some(expected) if expected == answer.answer => {
+                    self.correct += 1;
+                    "ok".to_owned()
+                }
+                Some(expected) => {
+                    self.wrong += 1;
+                    format!("WRONG, expected {expected}")
+                }
+                None if record => {
+                    self.recorded += 1;
+                    answers.insert(day.day, answer.part, answer.answer.clone());
+                    "recorded".to_owned()
+                }
+                None => {
+                    self.unknown += 1;
+                    "unknown".to_owned()
+                }
+            };
+            println!(
+                "Day {:>2} part {} ({}): {} {result}",
+                day.day,
+                answer.part,
+                day.name(),
+                answer.answer
+            );
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.wrong == 0
+    }
+}
+
+#[test]
+fn test_check() {
+    use crate::days::{Answer, DAYS};
+    use std::time::Duration;
+
+    let report = Report {
+        parse: Duration::ZERO,
+        answers: [(1, "10"), (2, "20")]
+            .map(|(part, answer)| Answer {
+                part,
+                answer: answer.into(),
+                solve: Duration::ZERO,
+            })
+            .to_vec(),
+    };
+    let mut answers = Answers::new();
+    answers.insert(1, 1, "10");
+    let mut summary = Summary::default();
+    summary.check(&mut answers, &DAYS[0], &report, false);
+    assert_eq!((summary.correct, summary.unknown), (1, 1));
+    summary.check(&mut answers, &DAYS[0], &report, true);
+    assert_eq!((summary.correct, summary.recorded), (2, 1));
+    assert_eq!(answers.get(1, 2), Some("20"));
+    answers.insert(1, 1, "11");
+    summary.check(&mut answers, &DAYS[0], &report, false);
+    assert_eq!(summary.wrong, 1);
+    assert!(!summary.passed());
+}
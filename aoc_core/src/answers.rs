@@ -0,0 +1,207 @@
+use crate::{ParseError, Solver, Source};
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind},
+    path::Path,
+};
+
+/// The accepted answers for the `input` files of the days.
+///
+/// They are stored in a small subset of TOML with one table per day holding the answers of its
+/// parts. Answers can be given as strings or as integers:
+///
+/// ```toml
+/// [day_05]
+/// part1 = "51752125"
+/// part2 = 12634632
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Answers {
+    answers: BTreeMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// The answers file at the root of the workspace. Answers are private, so it is not committed.
+    pub const PATH: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.toml");
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads the answers from `path`, a missing file holding no answers at all.
+    ///
+    /// A malformed file is reported as an [`io::ErrorKind::InvalidData`] error wrapping the
+    /// [`ParseError`].
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut answers = Self::new();
+        let mut day = None;
+        for (i, raw) in text.lines().enumerate() {
+            let line = strip_comment(raw).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let result = if let Some(table) = line.strip_prefix('[') {
+                parse_table(line, table).map(|d| day = Some(d))
+            } else {
+                parse_answer(line).and_then(|(part, answer)| {
+                    let day = day.ok_or_else(|| {
+                        ParseError::in_text(line, line, "a `[day_XX]` table first")
+                    })?;
+                    match answers.insert(day, part, answer) {
+                        Some(_) => Err(ParseError::in_text(line, line, "a part given only once")),
+                        None => Ok(()),
+                    }
+                })
+            };
+            result.map_err(|e| e.within(raw, line).on_line(i + 1))?;
+        }
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Sets the answer of a part, returning the answer it replaces.
+    pub fn insert(&mut self, day: u8, part: u8, answer: impl Into<String>) -> Option<String> {
+        self.answers.insert((day, part), answer.into())
+    }
+
+    pub fn len(&self) -> usize {
+        self.answers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut last = None;
+        for (&(day, part), answer) in &self.answers {
+            if last != Some(day) {
+                if last.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day_{day:02}]")?;
+                last = Some(day);
+            }
+            writeln!(f, "part{part} = \"{answer}\"")?;
+        }
+        Ok(())
+    }
+}
+
+/// Drops a `#` comment unless it is part of a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parses a `[day_XX]` table header, `table` being the line after the opening bracket.
+fn parse_table(line: &str, table: &str) -> Result<u8, ParseError> {
+    let name = table
+        .strip_suffix(']')
+        .ok_or_else(|| ParseError::in_text(line, line, "a table header like `[day_05]`"))?
+        .trim();
+    name.strip_prefix("day_")
+        .and_then(|d| d.parse().ok())
+        .filter(|d| (1..=25).contains(d))
+        .ok_or_else(|| ParseError::in_text(line, name, "a day from `day_01` to `day_25`"))
+}
+
+/// Parses a `partN = answer` line.
+fn parse_answer(line: &str) -> Result<(u8, String), ParseError> {
+    let (key, value) = line
+        .split_once('=')
+        .ok_or_else(|| ParseError::in_text(line, line, "`part1 = ...` or `part2 = ...`"))?;
+    let (key, value) = (key.trim(), value.trim());
+    let part = match key {
+        "part1" => 1,
+        "part2" => 2,
+        _ => return Err(ParseError::in_text(line, key, "`part1` or `part2`")),
+    };
+    let answer = match value.strip_prefix('"') {
+        Some(quoted) => quoted
+            .strip_suffix('"')
+            .filter(|a| !a.contains('"') && !a.contains('\\'))
+            .ok_or_else(|| ParseError::in_text(line, value, "a string without escapes"))?,
+        None if value
+            .strip_prefix('-')
+            .unwrap_or(value)
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            && !value.is_empty() =>
+        {
+            value
+        }
+        None => return Err(ParseError::in_text(line, value, "a string or an integer")),
+    };
+    Ok((part, answer.to_owned()))
+}
+
+/// Checks the answers of both parts for the `input` and settings in `dir` against the answers of `day` in the
+/// workspace's answers file.
+///
+/// Answers are private, so parts without an answer are not checked and pass.
+///
+/// # Panics
+/// If the answers or the input can not be read or a part does not produce the expected answer.
+pub fn check_answers<S: Solver>(day: u8, dir: impl AsRef<Path>) {
+    let answers = Answers::load(Answers::PATH).expect("Readable answers file");
+    let (part1, part2) = (answers.get(day, 1), answers.get(day, 2));
+    if part1.is_none() && part2.is_none() {
+        return;
+    }
+    let mut input =
+        S::read(&Source::Path(dir.as_ref().join("input"))).expect("Readable puzzle input");
+    S::configure(&mut input, dir.as_ref()).expect("Readable settings");
+    if let Some(expected) = part1 {
+        assert_eq!(S::part1(&input).to_string(), expected, "Answer of part 1");
+    }
+    if let Some(expected) = part2 {
+        assert_eq!(S::part2(&input).to_string(), expected, "Answer of part 2");
+    }
+}
+
+#[test]
+fn test_answers() {
+    let text = "# accepted answers\n[day_05]\npart1 = \"51752125\" # first try\npart2 = 12634632\n\n[ day_09 ]\npart2 = \"-3\"\n";
+    let answers = Answers::parse(text).unwrap();
+    assert_eq!(answers.len(), 3);
+    assert_eq!(answers.get(5, 1), Some("51752125"));
+    assert_eq!(answers.get(5, 2), Some("12634632"));
+    assert_eq!((answers.get(9, 1), answers.get(9, 2)), (None, Some("-3")));
+    assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+
+    let e = Answers::parse("[day_01]\npart3 = 4").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 1, "part3"));
+    let e = Answers::parse("part1 = 4").unwrap_err();
+    assert_eq!(e.line, 1);
+    let e = Answers::parse("[day_26]").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (2, "day_26"));
+    assert!(Answers::parse("[day_01]\npart1 = 4\npart1 = 5").is_err());
+    assert!(Answers::parse("[day_01]\npart1 = four").is_err());
+}
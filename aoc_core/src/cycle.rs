@@ -0,0 +1,125 @@
+//! Cycle detection for simulations that end up repeating their states.
+
+/// Where the sequence of states of a simulation starts to repeat.
+///
+/// The `n`-th state is the one reached after `n` steps, the start being the 0-th state. The
+/// states from `prefix` on repeat every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The index of the first state equal to the `n`-th state.
+    pub fn index(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+}
+
+/// Finds the cycle of the states reached by repeatedly applying `step` to `start`.
+///
+/// Gives up and returns `None` after `limit` steps without detecting the cycle.
+pub fn find_cycle<S: Clone + Eq>(
+    start: &S,
+    step: impl FnMut(&S) -> S,
+    limit: usize,
+) -> Option<Cycle> {
+    find_cycle_by_key(start, step, S::clone, limit)
+}
+
+/// Finds the cycle like [`find_cycle`], comparing states by the fingerprint `key` returns.
+///
+/// Uses Brent's algorithm, which only ever keeps two states in memory.
+pub fn find_cycle_by_key<S: Clone, K: Eq>(
+    start: &S,
+    mut step: impl FnMut(&S) -> S,
+    mut key: impl FnMut(&S) -> K,
+    limit: usize,
+) -> Option<Cycle> {
+    // Find the period by moving the tortoise to the hare at every power of two.
+    let (mut power, mut period) = (1, 1);
+    let mut tortoise = key(start);
+    let mut hare = step(start);
+    let mut hare_key = key(&hare);
+    let mut steps = 1;
+    while tortoise != hare_key {
+        if steps >= limit {
+            return None;
+        }
+        if power == period {
+            tortoise = hare_key;
+            power *= 2;
+            period = 0;
+        }
+        hare = step(&hare);
+        hare_key = key(&hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // With the hare a period ahead both meet at the start of the cycle.
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..period {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+    Some(Cycle { prefix, period })
+}
+
+/// The state reached after `n` steps, skipping over whole cycles.
+pub fn nth<S: Clone + Eq>(start: S, mut step: impl FnMut(&S) -> S, n: usize) -> S {
+    let n = find_cycle(&start, &mut step, n).map_or(n, |cycle| cycle.index(n));
+    (0..n).fold(start, |state, _| step(&state))
+}
+
+#[test]
+fn test_find_cycle() {
+    // 0, 1, 2, 3, 4, 2, 3, 4, ...
+    let step = |s: &u32| if *s == 4 { 2 } else { s + 1 };
+    let cycle = find_cycle(&0, step, 100).unwrap();
+    assert_eq!(
+        cycle,
+        Cycle {
+            prefix: 2,
+            period: 3
+        }
+    );
+    assert_eq!(cycle.index(7), 4);
+    assert_eq!(find_cycle(&0, step, 3), None);
+    assert_eq!(
+        find_cycle(&5, |s| s * 3 % 7, 100),
+        Some(Cycle {
+            prefix: 0,
+            period: 6
+        })
+    );
+    assert_eq!(
+        find_cycle_by_key(&0u64, |s| s + 1, |s| s % 10, 100),
+        Some(Cycle {
+            prefix: 0,
+            period: 10
+        })
+    );
+}
+
+#[test]
+fn test_nth() {
+    let step = |s: &u32| if *s == 4 { 2 } else { s + 1 };
+    assert_eq!(nth(0, step, 3), 3);
+    assert_eq!(
+        nth(0, step, 1_000_000_000),
+        [2, 3, 4][(1_000_000_000 - 2) % 3]
+    );
+    assert_eq!(nth(0u64, |s| s + 1, 1000), 1000);
+}
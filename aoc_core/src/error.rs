@@ -0,0 +1,148 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+    str::FromStr,
+};
+
+/// An error in the puzzle input pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line of the token starting at 1, 0 if unknown
+    pub line: usize,
+    /// The column the token starts at starting at 1, 0 if unknown
+    pub column: usize,
+    /// The offending token, empty if the input ended too early
+    pub token: String,
+    /// A description of what was expected instead of the token
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for `token` whose position is not yet known.
+    pub fn new(token: impl Into<String>, expected: impl Into<String>) -> Self {
+        Self {
+            line: 0,
+            column: 0,
+            token: token.into(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Creates an error for input that ended before `expected` was found.
+    pub fn end(expected: impl Into<String>) -> Self {
+        Self::new("", expected)
+    }
+
+    /// Creates an error for `token` pointing at its column in `text`.
+    ///
+    /// `token` is expected to be a slice of `text`, otherwise the column stays unknown.
+    pub fn in_text(text: &str, token: &str, expected: impl Into<String>) -> Self {
+        Self::new(token, expected).within(text, token)
+    }
+
+    /// Places the error at the given line and column.
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = line;
+        self.column = column;
+        self
+    }
+
+    /// Places the error on the given line if it is not known yet.
+    pub fn on_line(mut self, line: usize) -> Self {
+        if self.line == 0 {
+            self.line = line;
+        }
+        self
+    }
+
+    /// Translates the column of an error raised while parsing `part` to a column in `text`.
+    ///
+    /// `part` is expected to be a slice of `text`, otherwise the error is left untouched.
+    pub fn within(mut self, text: &str, part: &str) -> Self {
+        let start = text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= text.len() {
+            self.column = match self.column {
+                0 => offset + 1,
+                c => c + offset,
+            };
+        }
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (0, _) => {}
+            (l, 0) => write!(f, "line {l}: ")?,
+            (l, c) => write!(f, "line {l}, column {c}: ")?,
+        }
+        if self.token.is_empty() {
+            write!(f, "expected {} but the input ended", self.expected)
+        } else {
+            write!(f, "expected {} but found `{}`", self.expected, self.token)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(value: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, value)
+    }
+}
+
+/// Parses `token` which is a slice of `text`, reporting its column in `text` on failure.
+pub fn parse_token<T: FromStr>(text: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::in_text(text, token, expected))
+}
+
+/// Checks that `text` only consists of the `allowed` characters, reporting the first other one.
+pub fn check_chars(text: &str, allowed: &str, expected: &str) -> Result<(), ParseError> {
+    match text.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+        Some((i, c)) => Err(ParseError::in_text(
+            text,
+            &text[i..i + c.len_utf8()],
+            expected,
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Iterates over the lines of the input together with their line number starting at 1.
+///
+/// Lines that can not be read, e.g. because they are not valid UTF-8, are reported as errors.
+pub fn lines(input: impl BufRead) -> impl Iterator<Item = Result<(usize, String), ParseError>> {
+    input.lines().enumerate().map(|(i, l)| {
+        l.map(|l| (i + 1, l))
+            .map_err(|e| ParseError::new(e.to_string(), "a line of UTF-8 text").at(i + 1, 0))
+    })
+}
+
+#[test]
+fn test_error_position() {
+    let line = "R 6 (#70c710)";
+    let e = ParseError::in_text(line, &line[2..3], "a direction");
+    assert_eq!(e.column, 3);
+    let e = e.on_line(4);
+    assert_eq!(
+        e.to_string(),
+        "line 4, column 3: expected a direction but found `6`"
+    );
+    let part = &line[4..];
+    let e = ParseError::in_text(part, &part[2..], "a color").within(line, part);
+    assert_eq!(e.column, 7);
+    assert_eq!(
+        ParseError::end("a bid").to_string(),
+        "expected a bid but the input ended"
+    );
+    let e = check_chars("..#.x", ".#", "a tile").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (5, "x"));
+    let e = parse_token::<u32>(line, &line[4..], "a number").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (5, "(#70c710)"));
+}
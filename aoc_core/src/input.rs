@@ -0,0 +1,79 @@
+use std::{
+    fmt::{self, Display},
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+};
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A file, usually the `input` or `sample` file of a day
+    Path(PathBuf),
+    /// The standard input of the process
+    Stdin,
+    /// An input held in memory
+    Text(String),
+}
+
+impl Source {
+    /// Selects the source from a command line argument.
+    ///
+    /// Without an argument the `input` file of the working directory is used, `--sample` selects
+    /// the `sample` file and `-` reads from stdin. Any other argument is treated as a path.
+    pub fn from_arg(arg: Option<String>) -> Self {
+        match arg.as_deref() {
+            None => Source::Path("input".into()),
+            Some("--sample") => Source::Path("sample".into()),
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(path.into()),
+        }
+    }
+
+    /// An input held in memory, like one produced by a generator.
+    pub fn text(text: impl Into<String>) -> Self {
+        Source::Text(text.into())
+    }
+
+    pub fn open(&self) -> io::Result<Box<dyn BufRead + '_>> {
+        Ok(match self {
+            Source::Path(path) => Box::new(BufReader::new(File::open(path)?)),
+            Source::Stdin => Box::new(io::stdin().lock()),
+            Source::Text(text) => Box::new(text.as_bytes()),
+        })
+    }
+}
+
+/// Names the source for messages, the path of a file.
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Stdin => write!(f, "stdin"),
+            Source::Text(_) => write!(f, "text"),
+        }
+    }
+}
+
+#[test]
+fn test_from_arg() {
+    assert_eq!(Source::from_arg(None), Source::Path("input".into()));
+    assert_eq!(
+        Source::from_arg(Some("--sample".into())),
+        Source::Path("sample".into())
+    );
+    assert_eq!(Source::from_arg(Some("-".into())), Source::Stdin);
+    assert_eq!(
+        Source::from_arg(Some("../input".into())),
+        Source::Path("../input".into())
+    );
+}
+
+#[test]
+fn test_open_text() {
+    let source = Source::text("a\nb\n");
+    let lines: Vec<String> = source.open().unwrap().lines().map(Result::unwrap).collect();
+    assert_eq!(lines, ["a", "b"]);
+    assert_eq!(source.to_string(), "text");
+    assert_eq!(Source::Path("day/input".into()).to_string(), "day/input");
+}
@@ -0,0 +1,60 @@
+//! Dense ids for the names puzzles use to refer to their nodes.
+use std::collections::HashMap;
+
+/// Hands out ids for names in the order they are first seen, so the ids of `n` names are
+/// `0..n` and can index flat vectors.
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The id of the name, which is assigned to it when it is first seen.
+    pub fn intern(&mut self, name: &str) -> u32 {
+        if let Some(id) = self.ids.get(name) {
+            return *id;
+        }
+        let id = u32::try_from(self.names.len()).expect("At most u32::MAX names");
+        self.ids.insert(name.to_owned(), id);
+        self.names.push(name.to_owned());
+        id
+    }
+
+    /// The id of the name, `None` if it has not been interned.
+    pub fn get(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    /// The name with the given id.
+    ///
+    /// # Panics
+    /// If the id was not handed out by this interner.
+    pub fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+
+    /// The number of names, which is also the first id not handed out yet.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[test]
+fn test_interner() {
+    let mut names = Interner::new();
+    assert_eq!(names.intern("broadcaster"), 0);
+    assert_eq!(names.intern("rx"), 1);
+    assert_eq!(names.intern("broadcaster"), 0);
+    assert_eq!((names.get("rx"), names.get("zzz")), (Some(1), None));
+    assert_eq!(names.name(1), "rx");
+    assert_eq!(names.len(), 2);
+}
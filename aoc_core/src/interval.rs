@@ -0,0 +1,322 @@
+//! Half-open integer intervals, sets of them and the boxes they span.
+use std::ops::{Add, Range, Sub};
+
+/// The integer types intervals are made of.
+pub trait Bound: Copy + Ord + Add<Output = Self> + Sub<Output = Self> {}
+
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Bound for T {}
+
+/// The integers from `start` up to but excluding `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Interval<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Bound> Interval<T> {
+    /// The interval `start..end`, which is empty if `end` is not after `start`.
+    pub fn new(start: T, end: T) -> Self {
+        Self {
+            start,
+            end: end.max(start),
+        }
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> T {
+        self.end
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    pub fn len(&self) -> T {
+        self.end - self.start
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The values in both intervals, `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(self.start.max(other.start), self.end.min(other.end)).non_empty()
+    }
+
+    /// The values below `at` and the values from `at` on, `None` for an empty part.
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        let at = at.clamp(self.start, self.end);
+        (
+            Self::new(self.start, at).non_empty(),
+            Self::new(at, self.end).non_empty(),
+        )
+    }
+
+    /// The values below and above `other` that are not part of it.
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if other.is_empty() {
+            return (self.non_empty(), None);
+        }
+        (self.split_at(other.start).0, self.split_at(other.end).1)
+    }
+
+    /// Moves the interval so that `from` ends up at `to`.
+    ///
+    /// For unsigned types `from` must not be above the start of the interval.
+    pub fn shift(&self, from: T, to: T) -> Self {
+        Self::new(self.start - from + to, self.end - from + to)
+    }
+
+    fn non_empty(self) -> Option<Self> {
+        (!self.is_empty()).then_some(self)
+    }
+}
+
+impl<T: Bound> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// A set of integers stored as sorted and disjoint intervals that do not touch each other.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Bound> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// The intervals of the set in ascending order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn insert(&mut self, interval: Interval<T>) {
+        self.intervals.push(interval);
+        self.normalize();
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        while let (Some(i), Some(j)) = (x, y) {
+            intervals.extend(i.intersection(j));
+            // Drop whichever interval ends first, it can not overlap any later one.
+            if i.end < j.end {
+                x = a.next();
+            } else {
+                y = b.next();
+            }
+        }
+        Self { intervals }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        for interval in &self.intervals {
+            let mut rest = Some(*interval);
+            for other in &other.intervals {
+                let Some(r) = rest else {
+                    break;
+                };
+                let (below, above) = r.difference(other);
+                intervals.extend(below);
+                rest = above;
+            }
+            intervals.extend(rest);
+        }
+        Self { intervals }
+    }
+
+    /// The values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let (mut below, mut above) = (Vec::new(), Vec::new());
+        for interval in &self.intervals {
+            let (b, a) = interval.split_at(at);
+            below.extend(b);
+            above.extend(a);
+        }
+        (Self { intervals: below }, Self { intervals: above })
+    }
+
+    /// Moves every value of the set by the distance from `from` to `to`.
+    ///
+    /// For unsigned types `from` must not be above the smallest value of the set.
+    pub fn shift(&self, from: T, to: T) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.shift(from, to)).collect(),
+        }
+    }
+
+    /// Restores the order of the intervals, merging overlapping and adjacent ones and dropping
+    /// empty ones.
+    fn normalize(&mut self) {
+        self.intervals.retain(|i| !i.is_empty());
+        self.intervals.sort_unstable_by_key(|i| i.start);
+        let mut merged: Vec<Interval<T>> = Vec::with_capacity(self.intervals.len());
+        for interval in self.intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        self.intervals = merged;
+    }
+}
+
+impl<T: Bound> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = Self {
+            intervals: iter.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+impl<T: Bound> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        Self::from_iter([interval])
+    }
+}
+
+/// The points of an `N` dimensional space that lie within one interval on every axis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntervalBox<T, const N: usize> {
+    axes: [Interval<T>; N],
+}
+
+impl<T: Bound, const N: usize> IntervalBox<T, N> {
+    pub fn new(axes: [Interval<T>; N]) -> Self {
+        Self { axes }
+    }
+
+    pub fn axis(&self, axis: usize) -> Interval<T> {
+        self.axes[axis]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.axes.iter().any(Interval::is_empty)
+    }
+
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.axes.iter().zip(point).all(|(i, v)| i.contains(v))
+    }
+
+    /// The points in both boxes, `None` if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut axes = self.axes;
+        for (a, b) in axes.iter_mut().zip(&other.axes) {
+            *a = a.intersection(b)?;
+        }
+        Some(Self { axes })
+    }
+
+    /// The points below `at` and the points from `at` on along `axis`, `None` for an empty part.
+    pub fn split_at(&self, axis: usize, at: T) -> (Option<Self>, Option<Self>) {
+        let (below, above) = self.axes[axis].split_at(at);
+        let with = |interval: Interval<T>| {
+            let mut axes = self.axes;
+            axes[axis] = interval;
+            Self { axes }
+        };
+        (below.map(with), above.map(with))
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> u128
+    where
+        T: Into<u128>,
+    {
+        self.axes.iter().map(|i| i.len().into()).product()
+    }
+}
+
+#[test]
+fn test_interval() {
+    let i = Interval::new(2u64, 8);
+    assert_eq!(i.len(), 6);
+    assert!(Interval::new(5, 3).is_empty());
+    assert!(i.contains(2) && !i.contains(8));
+    assert_eq!(i.intersection(&(6..10).into()), Some(Interval::new(6, 8)));
+    assert_eq!(i.intersection(&(8..10).into()), None);
+    assert_eq!(i.split_at(4), (Some((2..4).into()), Some((4..8).into())));
+    assert_eq!(i.split_at(1), (None, Some(i)));
+    assert_eq!(
+        i.difference(&(4..6).into()),
+        (Some((2..4).into()), Some((6..8).into()))
+    );
+    assert_eq!(i.difference(&(0..10).into()), (None, None));
+    assert_eq!(i.shift(2, 10), (10..16).into());
+}
+
+#[test]
+fn test_interval_set() {
+    let set: IntervalSet<i32> = [(5..8).into(), (0..2).into(), (1..3).into(), (8..9).into()]
+        .into_iter()
+        .collect();
+    assert_eq!(set.intervals(), [(0..3).into(), (5..9).into()]);
+    assert!(set.contains(6) && !set.contains(4) && !set.contains(9));
+    let other: IntervalSet<i32> = [(2..6).into(), (7..20).into()].into_iter().collect();
+    assert_eq!(set.union(&other).intervals(), [Interval::from(0..20)]);
+    assert_eq!(
+        set.intersection(&other).intervals(),
+        [(2..3).into(), (5..6).into(), (7..9).into()]
+    );
+    assert_eq!(
+        set.difference(&other).intervals(),
+        [(0..2).into(), (6..7).into()]
+    );
+    let (below, above) = set.split_at(6);
+    assert_eq!(below.intervals(), [(0..3).into(), (5..6).into()]);
+    assert_eq!(above.intervals(), [Interval::from(6..9)]);
+    assert_eq!(set.shift(0, -3).min(), Some(-3));
+    let mut set = IntervalSet::new();
+    set.insert((3..4).into());
+    set.insert((4..5).into());
+    assert_eq!(set, Interval::from(3..5).into());
+}
+
+#[test]
+fn test_interval_box() {
+    let cube = IntervalBox::new([(0u32..10).into(); 3]);
+    assert_eq!(cube.volume(), 1000);
+    assert!(cube.contains([0, 9, 5]) && !cube.contains([0, 10, 5]));
+    let (below, above) = cube.split_at(1, 3);
+    assert_eq!(below.unwrap().volume(), 300);
+    assert_eq!(above.unwrap().axis(1), (3..10).into());
+    assert_eq!(cube.split_at(0, 10).1, None);
+    let other = IntervalBox::new([(5..20).into(), (0..2).into(), (0..10).into()]);
+    assert_eq!(cube.intersection(&other).unwrap().volume(), 100);
+}
@@ -0,0 +1,65 @@
+mod answers;
+pub mod cycle;
+mod error;
+mod input;
+pub mod interner;
+pub mod interval;
+pub mod math;
+pub mod pool;
+pub mod rng;
+pub mod search;
+pub use answers::{check_answers, Answers};
+pub use error::{check_chars, lines, parse_token, ParseError};
+pub use input::Source;
+use std::{fmt::Display, io, io::BufRead, path::Path};
+
+/// The solution to the puzzle of a single day.
+///
+/// The input is parsed once and shared by both parts, which allows runners to time parsing and
+/// solving separately.
+pub trait Solver {
+    /// The parsed puzzle input.
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Applies the settings a day keeps in files next to its `input` in `dir` to the parsed
+    /// input, like the bag of cubes of day 2. Most days have none.
+    fn configure(_input: &mut Self::Input, _dir: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Parses an input held in memory.
+    fn parse_str(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse(input.as_bytes())
+    }
+
+    /// Reads the input from the given source and parses it.
+    ///
+    /// Malformed input is reported as an [`io::ErrorKind::InvalidData`] error wrapping the
+    /// [`ParseError`].
+    fn read(source: &Source) -> io::Result<Self::Input> {
+        Ok(Self::parse(source.open()?)?)
+    }
+
+    /// Reads the input like [`read`](Self::read) for the binaries of the days, exiting with the
+    /// error if it is missing or malformed.
+    fn read_or_exit(source: &Source) -> Self::Input {
+        or_exit(Self::read(source), source)
+    }
+}
+
+/// The value of `result`, or the error reported on stderr for `what` failed and the process
+/// exiting with a failure.
+///
+/// The binaries of the days report bad input this way instead of panicking with a backtrace.
+pub fn or_exit<T, E: Display>(result: Result<T, E>, what: impl Display) -> T {
+    result.unwrap_or_else(|e| {
+        eprintln!("{what}: {e}");
+        std::process::exit(1)
+    })
+}
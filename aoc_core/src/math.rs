@@ -0,0 +1,165 @@
+//! Number theory shared by the days that combine cycles of different lengths.
+use std::ops::{Div, Rem};
+
+/// The unsigned integer types the functions of this module work on.
+pub trait Unsigned: Copy + Eq + Div<Output = Self> + Rem<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! unsigned {
+    ($($t:ty),*) => {
+        $(impl Unsigned for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+unsigned!(u8, u16, u32, u64, u128, usize);
+
+/// The greatest common divisor of `a` and `b`, `gcd(0, 0)` is 0.
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple of `a` and `b`, `None` if it does not fit into `T`.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// The greatest common divisor of all values, 0 for no values.
+pub fn gcd_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> T {
+    values.into_iter().fold(T::ZERO, gcd)
+}
+
+/// The least common multiple of all values, 1 for no values and `None` if it does not fit into `T`.
+pub fn lcm_all<T: Unsigned>(values: impl IntoIterator<Item = T>) -> Option<T> {
+    values.into_iter().try_fold(T::ONE, lcm)
+}
+
+/// Solves the system of congruences `x ≡ residue (mod modulus)` given as `(residue, modulus)`.
+///
+/// The moduli do not have to be coprime. The solution is returned as the smallest non negative `x`
+/// together with the least common multiple of all moduli, every solution is `x` plus a multiple of
+/// it. `None` if the congruences contradict each other, a modulus is 0 or the least common
+/// multiple does not fit into a `u128`.
+pub fn crt(congruences: impl IntoIterator<Item = (u128, u128)>) -> Option<(u128, u128)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            if m2 == 0 {
+                return None;
+            }
+            let r2 = r2 % m2;
+            let g = gcd(m1, m2);
+            if r1 % g != r2 % g {
+                return None;
+            }
+            let m = lcm(m1, m2)?;
+            // Find k < m2 / g with r1 + m1 * k ≡ r2 (mod m2), dividing everything by g first.
+            let step = m2 / g;
+            let diff = if r2 >= r1 {
+                (r2 - r1) / g % step
+            } else {
+                (step - (r1 - r2) / g % step) % step
+            };
+            let k = mul_mod(diff, inverse(m1 / g % step, step)?, step);
+            // As r1 < m1 and k < m / m1 this stays below m.
+            Some((r1 + m1 * k, m))
+        })
+}
+
+/// `a * b mod m` without overflowing.
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    let (mut a, mut b) = (a % m, b % m);
+    if let Some(p) = a.checked_mul(b) {
+        return p % m;
+    }
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = add_mod(result, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    result
+}
+
+/// `a + b mod m` for `a, b < m` without overflowing.
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `a - b mod m` for `a, b < m`.
+fn sub_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// The multiplicative inverse of `a` modulo `m`, `None` if `a` and `m` are not coprime.
+fn inverse(a: u128, m: u128) -> Option<u128> {
+    if m == 1 {
+        return Some(0);
+    }
+    // The extended euclidean algorithm with the coefficients of `a` kept modulo `m`.
+    let (mut old_r, mut r) = (a, m);
+    let (mut old_s, mut s) = (1, 0);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, sub_mod(old_s, mul_mod(q, s, m), m));
+    }
+    (old_r == 1).then_some(old_s)
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(gcd(12u32, 18), 6);
+    assert_eq!(gcd(0u64, 7), 7);
+    assert_eq!(lcm(4usize, 6), Some(12));
+    assert_eq!(gcd_all([12u8, 18, 27]), 3);
+    assert_eq!(lcm_all::<u64>([]), Some(1));
+    assert_eq!(lcm_all([2u64, 3, 4, 5]), Some(60));
+    assert_eq!(lcm_all([u64::MAX, u64::MAX - 1]), None);
+    assert_eq!(
+        lcm_all([u64::MAX as u128, u64::MAX as u128 - 1]),
+        Some(u64::MAX as u128 * (u64::MAX as u128 - 1))
+    );
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    // Moduli that are not coprime.
+    assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(crt([(1, 4), (2, 6)]), None);
+    assert_eq!(crt([(0, 6), (0, 4)]), Some((0, 12)));
+    assert_eq!(crt([(1, 0)]), None);
+    assert_eq!(crt([]), Some((0, 1)));
+    let big = u64::MAX as u128;
+    assert_eq!(
+        crt([(big - 1, big), (1, big - 1)]),
+        Some((2 * big - 1, big * (big - 1)))
+    );
+}
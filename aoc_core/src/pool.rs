@@ -0,0 +1,350 @@
+//! A bounded pool of worker threads shared by the runner and the days splitting up their work.
+//!
+//! A thread waiting for other work only ever runs that work itself: [`Pool::map`] works through
+//! its own items with idle workers helping, and a task joined before a worker took it runs on the
+//! joining thread. So a day spreading its work over the pool can not block all workers while its
+//! jobs are stuck in the queue, and it never ends up running other days inside its own part.
+use std::{
+    collections::VecDeque,
+    num::NonZeroUsize,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, TryRecvError},
+        Arc, Condvar, Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+struct State {
+    /// The jobs waiting for a worker, each with the id of its task
+    jobs: VecDeque<(u64, Job)>,
+    /// Set once the pool is dropped, stopping the workers when the queue is empty
+    closed: bool,
+    /// Workers to stop after their job since others replaced them while it timed out
+    surplus: usize,
+}
+
+#[derive(Default)]
+struct Queue {
+    state: Mutex<State>,
+    available: Condvar,
+}
+
+impl Queue {
+    fn push(&self, id: u64, job: Job) {
+        self.state.lock().unwrap().jobs.push_back((id, job));
+        self.available.notify_one();
+    }
+
+    /// Takes the job of the task `id` out of the queue if no worker started it yet.
+    fn take(&self, id: u64) -> Option<Job> {
+        let mut state = self.state.lock().unwrap();
+        let i = state.jobs.iter().position(|(task, _)| *task == id)?;
+        state.jobs.remove(i).map(|(_, job)| job)
+    }
+
+    /// Waits for the next job, `None` once the pool is closed and all jobs are done.
+    fn pop(&self) -> Option<Job> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if let Some((_, job)) = state.jobs.pop_front() {
+                return Some(job);
+            }
+            if state.closed {
+                return None;
+            }
+            state = self.available.wait(state).unwrap();
+        }
+    }
+}
+
+/// A fixed number of worker threads running the jobs spawned on them in order.
+pub struct Pool {
+    queue: Arc<Queue>,
+    threads: usize,
+    /// The id of the next task
+    next: AtomicU64,
+}
+
+impl Pool {
+    /// Starts a pool with `threads` workers, at least one.
+    pub fn new(threads: usize) -> Self {
+        let threads = threads.max(1);
+        let queue = Arc::new(Queue::default());
+        for _ in 0..threads {
+            start_worker(Arc::clone(&queue));
+        }
+        Self {
+            queue,
+            threads,
+            next: AtomicU64::new(0),
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Queues `f` to run on a worker and returns the task to wait for its result.
+    pub fn spawn<T: Send + 'static>(&self, f: impl FnOnce() -> T + Send + 'static) -> Task<T> {
+        let (sender, result) = mpsc::channel();
+        let started = Arc::new(OnceLock::new());
+        let start = Arc::clone(&started);
+        let job = Box::new(move || {
+            start.get_or_init(Instant::now);
+            // A panic is passed on to the task instead of taking the worker down.
+            let _ = sender.send(panic::catch_unwind(AssertUnwindSafe(f)));
+        });
+        let id = self.next.fetch_add(1, Ordering::Relaxed);
+        self.queue.push(id, job);
+        Task {
+            id,
+            result,
+            started,
+            queue: Arc::clone(&self.queue),
+        }
+    }
+
+    /// Runs `f` for every item and returns the results in the order of the items.
+    ///
+    /// The calling thread works through the items itself, with the workers that are idle or
+    /// become idle before the items run out helping it.
+    ///
+    /// # Panics
+    /// If `f` panicked for an item, with its panic.
+    pub fn map<T, R, F>(&self, items: impl IntoIterator<Item = T>, f: F) -> Vec<R>
+    where
+        T: Send + 'static,
+        R: Send + 'static,
+        F: Fn(T) -> R + Send + Sync + 'static,
+    {
+        let items: VecDeque<(usize, T)> = items.into_iter().enumerate().collect();
+        let len = items.len();
+        let batch = Arc::new(Batch {
+            f,
+            items: Mutex::new(items),
+            results: Mutex::new(((0..len).map(|_| None).collect(), len)),
+            finished: Condvar::new(),
+        });
+        // The calling thread is one of the hands working on the batch.
+        for _ in 1..self.threads.min(len) {
+            let batch = Arc::clone(&batch);
+            let id = self.next.fetch_add(1, Ordering::Relaxed);
+            self.queue.push(id, Box::new(move || batch.work()));
+        }
+        batch.work();
+        let mut results = batch.results.lock().unwrap();
+        while results.1 > 0 {
+            results = batch.finished.wait(results).unwrap();
+        }
+        results.0.drain(..).map(|r| unwind(r.unwrap())).collect()
+    }
+}
+
+/// The items of a [`Pool::map`] call, taken by the caller and the workers helping it.
+struct Batch<T, R, F> {
+    f: F,
+    /// The items no one took yet with their index
+    items: Mutex<VecDeque<(usize, T)>>,
+    /// The results by the index of their item, and how many of them are still missing
+    results: Mutex<(Vec<Option<thread::Result<R>>>, usize)>,
+    /// Notified once the last result is in
+    finished: Condvar,
+}
+
+impl<T, R, F: Fn(T) -> R> Batch<T, R, F> {
+    /// Runs items until there are none left to take.
+    fn work(&self) {
+        loop {
+            let Some((i, item)) = self.items.lock().unwrap().pop_front() else {
+                return;
+            };
+            let result = panic::catch_unwind(AssertUnwindSafe(|| (self.f)(item)));
+            let mut results = self.results.lock().unwrap();
+            results.0[i] = Some(result);
+            results.1 -= 1;
+            if results.1 == 0 {
+                self.finished.notify_all();
+            }
+        }
+    }
+}
+
+fn start_worker(queue: Arc<Queue>) {
+    thread::spawn(move || {
+        while let Some(job) = queue.pop() {
+            job();
+            // For every worker that was replaced one of the workers done with their job stops.
+            let mut state = queue.state.lock().unwrap();
+            if state.surplus > 0 {
+                state.surplus -= 1;
+                break;
+            }
+        }
+    });
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        self.queue.state.lock().unwrap().closed = true;
+        self.queue.available.notify_all();
+    }
+}
+
+/// The result of a job spawned on a [`Pool`].
+pub struct Task<T> {
+    id: u64,
+    result: Receiver<thread::Result<T>>,
+    /// When a worker started running the job
+    started: Arc<OnceLock<Instant>>,
+    queue: Arc<Queue>,
+}
+
+impl<T> Task<T> {
+    /// Waits for the result, running the job on the calling thread if no worker started it yet.
+    ///
+    /// # Panics
+    /// If the job panicked, with its panic.
+    pub fn join(self) -> T {
+        unwind(self.try_join())
+    }
+
+    /// Waits for the result like [`join`](Self::join), returning the panic of the job as an
+    /// error instead of resuming it.
+    pub fn try_join(self) -> thread::Result<T> {
+        if let Some(job) = self.queue.take(self.id) {
+            job();
+        }
+        self.result.recv().expect("The result of the job")
+    }
+
+    /// Waits for the result until the job has been running for `timeout`, `None` if it did not
+    /// finish by then.
+    ///
+    /// Unlike [`join`](Self::join) this does not run any queued jobs, which could take longer
+    /// than the timeout. A job that timed out keeps running, but another worker takes its place
+    /// for the jobs after it.
+    ///
+    /// # Panics
+    /// If the job panicked, with its panic.
+    pub fn join_timeout(self, timeout: Duration) -> Option<T> {
+        self.try_join_timeout(timeout).map(unwind)
+    }
+
+    /// Waits for the result like [`join_timeout`](Self::join_timeout), returning the panic of
+    /// the job as an error instead of resuming it.
+    pub fn try_join_timeout(self, timeout: Duration) -> Option<thread::Result<T>> {
+        loop {
+            match self.result.try_recv() {
+                Ok(result) => return Some(result),
+                Err(TryRecvError::Disconnected) => panic!("The job of the task was dropped"),
+                Err(TryRecvError::Empty) => {}
+            }
+            // The time spent in the queue does not count.
+            let wait = match self.started.get() {
+                Some(start) => match (*start + timeout).checked_duration_since(Instant::now()) {
+                    Some(left) => left,
+                    None => {
+                        self.queue.state.lock().unwrap().surplus += 1;
+                        start_worker(Arc::clone(&self.queue));
+                        return None;
+                    }
+                },
+                None => Duration::from_millis(10),
+            };
+            match self.result.recv_timeout(wait) {
+                Ok(result) => return Some(result),
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => panic!("The job of the task was dropped"),
+            }
+        }
+    }
+}
+
+fn unwind<T>(result: thread::Result<T>) -> T {
+    result.unwrap_or_else(|e| panic::resume_unwind(e))
+}
+
+static SHARED: OnceLock<Pool> = OnceLock::new();
+
+/// Sets the number of workers of the [`shared`] pool, returning `false` if it was already
+/// started with a different number.
+pub fn init(threads: usize) -> bool {
+    shared_or_init(|| threads).threads() == threads.max(1)
+}
+
+/// The pool shared by the whole program, with one worker per CPU unless [`init`] was called
+/// before.
+pub fn shared() -> &'static Pool {
+    shared_or_init(|| thread::available_parallelism().map_or(1, NonZeroUsize::get))
+}
+
+fn shared_or_init(threads: impl FnOnce() -> usize) -> &'static Pool {
+    SHARED.get_or_init(|| Pool::new(threads()))
+}
+
+#[test]
+fn test_map() {
+    let pool = Pool::new(3);
+    assert_eq!(
+        pool.map(0..100, |i: u64| i * i),
+        (0..100).map(|i| i * i).collect::<Vec<_>>()
+    );
+    assert_eq!(pool.spawn(|| "done").join(), "done");
+}
+
+#[test]
+fn test_nested() {
+    // The only worker runs the outer task, which has to run its own jobs while waiting.
+    let pool = Arc::new(Pool::new(1));
+    let inner = Arc::clone(&pool);
+    let outer = pool.spawn(move || inner.map(1..=10, |i: u32| i).into_iter().sum::<u32>());
+    assert_eq!(outer.join(), 55);
+}
+
+#[test]
+fn test_timeout() {
+    let pool = Pool::new(1);
+    let (release, blocked) = mpsc::channel::<()>();
+    let stuck = pool.spawn(move || blocked.recv().is_ok());
+    // Queued behind the stuck job, the second task only runs on the worker replacing it.
+    let queued = pool.spawn(|| thread::sleep(Duration::from_millis(50)));
+    assert_eq!(stuck.join_timeout(Duration::from_millis(20)), None);
+    assert_eq!(queued.join_timeout(Duration::from_secs(5)), Some(()));
+    release.send(()).unwrap();
+    // A job that finished is no timeout, however long ago it started.
+    let done = pool.spawn(|| 2);
+    thread::sleep(Duration::from_millis(40));
+    assert_eq!(done.join_timeout(Duration::from_millis(10)), Some(2));
+    let panicked = pool.spawn(|| panic!("boom"));
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| panicked.join())).is_err());
+    let panicked = pool.spawn(|| panic!("boom"));
+    let panic = panicked.try_join_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(panic.unwrap_err().downcast_ref::<&str>(), Some(&"boom"));
+    assert_eq!(pool.spawn(|| 1).join(), 1);
+    assert!(pool.spawn(|| panic!("boom")).try_join().is_err());
+}
+
+#[test]
+fn test_join_own() {
+    let pool = Pool::new(1);
+    let (release, blocked) = mpsc::channel::<()>();
+    let stuck = pool.spawn(move || blocked.recv().is_ok());
+    let (started, other) = mpsc::channel();
+    let unrelated = pool.spawn(move || started.send(()).unwrap());
+    // With the worker stuck only the joined task runs on this thread, not the one before it.
+    assert_eq!(pool.spawn(|| 3).join(), 3);
+    assert_eq!(pool.map([1, 2, 3], |i: u32| i * 2), [2, 4, 6]);
+    assert!(other.try_recv().is_err());
+    release.send(()).unwrap();
+    assert!(stuck.join());
+    unrelated.join();
+    assert!(other.try_recv().is_ok());
+    let panicked = panic::catch_unwind(AssertUnwindSafe(|| pool.map([0, 1], |i: u32| 1 / i)));
+    assert!(panicked.is_err());
+}
@@ -0,0 +1,69 @@
+//! A small seeded random number generator for the synthetic puzzle inputs.
+//!
+//! The same seed always produces the same numbers on every platform, so generated inputs can be
+//! reproduced from their seed alone. It is not suited for anything needing real randomness.
+use std::ops::Range;
+
+/// The SplitMix64 generator.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "Empty range {range:?}");
+        // The bias of the modulo is negligible for the small ranges of puzzle inputs.
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// An index below `len`, which must not be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..len as u64) as usize
+    }
+
+    /// `true` with a probability of `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(0..denominator) < numerator
+    }
+
+    /// A random element of `items`, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[test]
+fn test_rng() {
+    let mut a = Rng::new(7);
+    let mut b = Rng::new(7);
+    let numbers: Vec<u64> = (0..100).map(|_| a.range(10..20)).collect();
+    assert!(numbers.iter().all(|n| (10..20).contains(n)));
+    assert_eq!(
+        numbers,
+        (0..100).map(|_| b.range(10..20)).collect::<Vec<_>>()
+    );
+    assert_ne!(Rng::new(8).next_u64(), Rng::new(7).next_u64());
+    let mut items: Vec<u32> = (0..50).collect();
+    a.shuffle(&mut items);
+    items.sort_unstable();
+    assert_eq!(items, (0..50).collect::<Vec<_>>());
+    assert!((0..100).all(|_| !a.chance(0, 3) && a.chance(3, 3)));
+}
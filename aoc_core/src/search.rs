@@ -0,0 +1,357 @@
+//! Shortest path searches over states connected by a successor function.
+//!
+//! Paths are returned including the start and the goal.
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::{BuildHasherDefault, Hash, Hasher},
+    ops::{Add, Index},
+};
+
+/// The costs of the steps of a weighted search, the default value being no cost at all.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Finds a path with the fewest steps from `start` to a state for which `is_goal` holds.
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Vec<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (seen, goal) = breadth_first(start, successors, is_goal);
+    goal.map(|g| seen.path(g))
+}
+
+/// The fewest steps needed to reach every state reachable from `start`.
+pub fn bfs_distances<S, I>(start: S, successors: impl FnMut(&S) -> I) -> Distances<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    Distances(breadth_first(start, successors, |_| false).0)
+}
+
+/// Finds the cheapest path from `start` to a state for which `is_goal` holds and its cost.
+///
+/// The successor function yields the next states together with the cost of the step to them.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// The cost of the cheapest path to every state reachable from `start`.
+pub fn dijkstra_distances<S, C, I>(start: S, successors: impl FnMut(&S) -> I) -> Distances<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    Distances(best_first(start, successors, |_| C::default(), |_| false).0)
+}
+
+/// The cost of the cheapest path to every state like [`dijkstra_distances`], for states that
+/// `index` numbers below `len`, like the tiles of a grid.
+///
+/// The costs are kept in a vector instead of a hash map, `None` for the states not reached.
+pub fn dijkstra_distances_indexed<S, C, I>(
+    start: S,
+    len: usize,
+    index: impl Fn(&S) -> usize,
+    mut successors: impl FnMut(&S) -> I,
+) -> Vec<Option<C>>
+where
+    S: Clone,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut costs = vec![None; len];
+    let mut states = vec![None; len];
+    let mut closed = vec![false; len];
+    let i = index(&start);
+    costs[i] = Some(C::default());
+    states[i] = Some(start);
+    let mut heap = BinaryHeap::from([Reverse((C::default(), i))]);
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        // The state may have been queued again with a lower cost.
+        if closed[i] {
+            continue;
+        }
+        closed[i] = true;
+        let state = states[i].clone().expect("A state for every queued index");
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let j = index(&next);
+            if closed[j] || costs[j].is_some_and(|c| c <= next_cost) {
+                continue;
+            }
+            costs[j] = Some(next_cost);
+            states[j] = Some(next);
+            heap.push(Reverse((next_cost, j)));
+        }
+    }
+    costs
+}
+
+/// Finds the cheapest path like [`dijkstra`], exploring the states with the lowest estimated
+/// total cost first.
+///
+/// The `heuristic` estimates the cost from a state to the goal. For the path to be the cheapest
+/// it must never overestimate it and not drop by more than the cost of a step.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(Vec<S>, C)>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let (seen, goal) = best_first(start, successors, heuristic, is_goal);
+    goal.map(|g| (seen.path(g), seen.costs[g]))
+}
+
+/// A fast hasher for the small states of searches that do not need protection against hash
+/// flooding, following the one used by the Rust compiler.
+#[derive(Default)]
+struct FxHasher(u64);
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u64(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn write_usize(&mut self, word: usize) {
+        self.write_u64(word as u64);
+    }
+
+    fn write_u32(&mut self, word: u32) {
+        self.write_u64(word.into());
+    }
+
+    fn write_u8(&mut self, word: u8) {
+        self.write_u64(word.into());
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The states found by a search, each with the state it was reached from and its cost.
+struct Seen<S, C> {
+    states: Vec<S>,
+    index: HashMap<S, usize, BuildHasherDefault<FxHasher>>,
+    parents: Vec<usize>,
+    costs: Vec<C>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Seen<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        Self {
+            index: HashMap::from_iter([(start.clone(), 0)]),
+            states: vec![start],
+            parents: vec![0],
+            costs: vec![cost],
+        }
+    }
+
+    /// Adds a newly found state and returns its index.
+    fn push(&mut self, state: S, parent: usize, cost: C) -> usize {
+        let i = self.states.len();
+        self.index.insert(state.clone(), i);
+        self.states.push(state);
+        self.parents.push(parent);
+        self.costs.push(cost);
+        i
+    }
+
+    /// The states leading from the start to the state with index `i`.
+    fn path(&self, mut i: usize) -> Vec<S> {
+        let mut path = vec![self.states[i].clone()];
+        while i != 0 {
+            i = self.parents[i];
+            path.push(self.states[i].clone());
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// The cost of reaching every state found by a search.
+pub struct Distances<S, C>(Seen<S, C>);
+
+impl<S: Eq + Hash, C: Copy> Distances<S, C> {
+    pub fn get(&self, state: &S) -> Option<C> {
+        self.0.index.get(state).map(|i| self.0.costs[*i])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.states.is_empty()
+    }
+
+    /// The states in the order they were found together with their costs.
+    pub fn iter(&self) -> impl Iterator<Item = (&S, C)> {
+        self.0.states.iter().zip(self.0.costs.iter().copied())
+    }
+}
+
+impl<S: Eq + Hash, C: Copy> Index<&S> for Distances<S, C> {
+    type Output = C;
+
+    /// # Panics
+    /// If the state was not found by the search.
+    fn index(&self, state: &S) -> &C {
+        &self.0.costs[self.0.index[state]]
+    }
+}
+
+fn breadth_first<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Seen<S, usize>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = Seen::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        if is_goal(&seen.states[i]) {
+            return (seen, Some(i));
+        }
+        let steps = seen.costs[i] + 1;
+        for next in successors(&seen.states[i]) {
+            if !seen.index.contains_key(&next) {
+                queue.push_back(seen.push(next, i, steps));
+            }
+        }
+    }
+    (seen, None)
+}
+
+fn best_first<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> (Seen<S, C>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+    let mut seen = Seen::new(start, C::default());
+    let mut closed = vec![false];
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        // The state may have been queued again with a lower cost.
+        if closed[i] {
+            continue;
+        }
+        closed[i] = true;
+        if is_goal(&seen.states[i]) {
+            return (seen, Some(i));
+        }
+        for (next, step) in successors(&seen.states[i]) {
+            let next_cost = cost + step;
+            let j = match seen.index.get(&next) {
+                Some(&j) if closed[j] || seen.costs[j] <= next_cost => continue,
+                Some(&j) => {
+                    seen.parents[j] = i;
+                    seen.costs[j] = next_cost;
+                    j
+                }
+                None => {
+                    closed.push(false);
+                    seen.push(next, i, next_cost)
+                }
+            };
+            heap.push(Reverse((
+                next_cost + heuristic(&seen.states[j]),
+                next_cost,
+                j,
+            )));
+        }
+    }
+    (seen, None)
+}
+
+#[cfg(test)]
+fn test_graph(s: &u32) -> Vec<(u32, u32)> {
+    // 0 -> 1 -> 3 is shorter in steps but 0 -> 2 -> 1 -> 3 is cheaper.
+    match s {
+        0 => vec![(1, 5), (2, 1)],
+        1 => vec![(3, 1)],
+        2 => vec![(1, 1), (4, 10)],
+        _ => vec![],
+    }
+}
+
+#[test]
+fn test_bfs() {
+    let successors = |s: &u32| test_graph(s).into_iter().map(|(n, _)| n);
+    assert_eq!(bfs(0, successors, |s| *s == 3), Some(vec![0, 1, 3]));
+    assert_eq!(bfs(0, successors, |s| *s == 5), None);
+    let distances = bfs_distances(0, successors);
+    assert_eq!(distances.len(), 5);
+    assert_eq!((distances[&3], distances[&4]), (2, 2));
+}
+
+#[test]
+fn test_dijkstra() {
+    assert_eq!(
+        dijkstra(0, test_graph, |s| *s == 3),
+        Some((vec![0, 2, 1, 3], 3))
+    );
+    assert_eq!(dijkstra(0, test_graph, |s| *s == 5), None);
+    let distances = dijkstra_distances(0, test_graph);
+    assert_eq!((distances[&1], distances[&3], distances[&4]), (2, 3, 11));
+    let distances = dijkstra_distances_indexed(0, 6, |s| *s as usize, test_graph);
+    assert_eq!(
+        distances,
+        [Some(0), Some(2), Some(1), Some(3), Some(11), None]
+    );
+}
+
+#[test]
+fn test_astar() {
+    // Walk a 10x10 grid from one corner to the other, every step costing 1.
+    let successors = |&(x, y): &(i32, i32)| {
+        [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|(x, y)| (0..10).contains(x) && (0..10).contains(y))
+            .map(|p| (p, 1))
+    };
+    let heuristic = |&(x, y): &(i32, i32)| 18 - x - y;
+    let (path, cost) = astar((0, 0), successors, heuristic, |p| *p == (9, 9)).unwrap();
+    assert_eq!((path.len(), cost), (19, 18));
+    assert_eq!((path[0], path[18]), ((0, 0), (9, 9)));
+}
@@ -0,0 +1,152 @@
+//! An Aho–Corasick automaton finding all occurrences of a set of keys in a single pass.
+use crate::trie::Trie;
+use std::collections::VecDeque;
+
+/// Finds the keys occurring in a text along with their values, overlapping ones included.
+///
+/// The states are the nodes of the [`Trie`] of the keys, their tables of children completed
+/// by the failure links, so reading a byte is a single lookup. The empty key never matches.
+#[derive(Debug, Clone)]
+pub struct Automaton<V> {
+    /// The state after reading each byte in every state
+    next: Vec<[u32; 256]>,
+    /// The keys ending at every state, including those of the suffixes that are keys, longest
+    /// first
+    matches: Vec<Vec<usize>>,
+    /// The value and length of every key
+    keys: Vec<(V, usize)>,
+}
+
+/// An occurrence of a key, `start..end` being its bytes in the text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'a, V> {
+    pub start: usize,
+    pub end: usize,
+    pub value: &'a V,
+}
+
+impl<V> Automaton<V> {
+    /// Builds the automaton of the keys, a key given twice keeping its last value.
+    pub fn new<K: AsRef<[u8]>>(keys: impl IntoIterator<Item = (K, V)>) -> Self {
+        Trie::from_iter(keys).into()
+    }
+
+    /// Iterates over the occurrences of the keys ordered by their end, the longest first.
+    pub fn find_iter<'a>(&'a self, text: &'a (impl AsRef<[u8]> + ?Sized)) -> Matches<'a, V> {
+        Matches {
+            automaton: self,
+            text: text.as_ref(),
+            pos: 0,
+            state: 0,
+            pending: 0,
+        }
+    }
+}
+
+impl<V> From<Trie<V>> for Automaton<V> {
+    fn from(trie: Trie<V>) -> Self {
+        let (mut next, mut values): (Vec<_>, Vec<_>) = trie
+            .nodes
+            .into_iter()
+            .map(|n| (*n.children, n.value))
+            .unzip();
+        let mut matches = vec![Vec::new(); next.len()];
+        let mut keys = Vec::new();
+        // The length of the key of every state and the state of its longest proper suffix that
+        // is a prefix of a key as well
+        let mut depth = vec![0; next.len()];
+        let mut fail = vec![0; next.len()];
+        // Breadth first, so the states of the shorter suffixes are done before.
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            if let Some(value) = values[state].take().filter(|_| depth[state] > 0) {
+                matches[state].push(keys.len());
+                keys.push((value, depth[state]));
+            }
+            if state != 0 {
+                let inherited = matches[fail[state]].clone();
+                matches[state].extend(inherited);
+            }
+            let suffixes = if state == 0 {
+                [0; 256]
+            } else {
+                next[fail[state]]
+            };
+            for (b, suffix) in suffixes.into_iter().enumerate() {
+                match next[state][b] as usize {
+                    0 => next[state][b] = suffix,
+                    child => {
+                        depth[child] = depth[state] + 1;
+                        fail[child] = suffix as usize;
+                        queue.push_back(child);
+                    }
+                }
+            }
+        }
+        Self {
+            next,
+            matches,
+            keys,
+        }
+    }
+}
+
+/// The occurrences of the keys of an [`Automaton`] in a text, see [`Automaton::find_iter`].
+pub struct Matches<'a, V> {
+    automaton: &'a Automaton<V>,
+    text: &'a [u8],
+    /// The number of bytes read
+    pos: usize,
+    state: usize,
+    /// The number of matches of the state already returned
+    pending: usize,
+}
+
+impl<'a, V> Iterator for Matches<'a, V> {
+    type Item = Match<'a, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let matches = &self.automaton.matches[self.state];
+            if let Some(k) = matches.get(self.pending) {
+                self.pending += 1;
+                let (value, len) = &self.automaton.keys[*k];
+                return Some(Match {
+                    start: self.pos - len,
+                    end: self.pos,
+                    value,
+                });
+            }
+            let b = *self.text.get(self.pos)?;
+            self.state = self.automaton.next[self.state][b as usize] as usize;
+            self.pos += 1;
+            self.pending = 0;
+        }
+    }
+}
+
+#[test]
+fn test_automaton() {
+    let automaton = Automaton::new([("he", 1), ("she", 2), ("his", 3), ("hers", 4), ("", 5)]);
+    let matches: Vec<(usize, usize, i32)> = automaton
+        .find_iter("ushers")
+        .map(|m| (m.start, m.end, *m.value))
+        .collect();
+    assert_eq!(matches, [(1, 4, 2), (2, 4, 1), (2, 6, 4)]);
+    let automaton = Automaton::new([("one", 1), ("eight", 8), ("one", 11)]);
+    let matches: Vec<(usize, usize, i32)> = automaton
+        .find_iter("xoneightone")
+        .map(|m| (m.start, m.end, *m.value))
+        .collect();
+    assert_eq!(matches, [(1, 4, 11), (3, 8, 8), (8, 11, 11)]);
+    assert_eq!(automaton.find_iter("").count(), 0);
+    // The nodes freed by removing a key are left unreachable.
+    let mut trie = crate::trie!("one" => 1, "oneight" => 18, "eight" => 8);
+    trie.remove("oneight");
+    let automaton = Automaton::from(trie);
+    let matches: Vec<(usize, usize, i32)> = automaton
+        .find_iter("oneight")
+        .map(|m| (m.start, m.end, *m.value))
+        .collect();
+    assert_eq!(matches, [(0, 3, 1), (2, 7, 8)]);
+}
@@ -0,0 +1,165 @@
+//! Calibration values made of the first and last digit of a line, with configurable notions of
+//! what a digit is.
+use crate::automaton::{Automaton, Match};
+use aoc_core::{lines, parse_token, pool, ParseError};
+use std::{io::BufRead, sync::Arc};
+
+/// The number of lines a job of [`Calibrator::sum`] scans.
+const CHUNK: usize = 256;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// An occurrence of a digit in a line, `start..end` being its bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Digit {
+    pub value: u64,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// The first and last digit of a line, the same one if there is only one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Calibration {
+    pub first: Digit,
+    pub last: Digit,
+}
+
+impl Calibration {
+    /// The number written by the first digit followed by the last one, like 1212 for a line
+    /// starting and ending with `twelve`.
+    pub fn value(&self) -> u64 {
+        let mut shift = 10;
+        while shift <= self.last.value {
+            shift *= 10;
+        }
+        self.first.value * shift + self.last.value
+    }
+}
+
+/// Finds the calibrations of lines, the mode deciding which words count as digits.
+#[derive(Debug, Clone)]
+pub struct Calibrator {
+    automaton: Arc<Automaton<u64>>,
+}
+
+impl Calibrator {
+    /// Only the numerical digits count, as in the first part.
+    pub fn digits() -> Self {
+        Self::with_words(std::iter::empty::<(&str, u64)>())
+    }
+
+    /// The digits spelled out in English count as well, as in the second part.
+    pub fn words() -> Self {
+        Self::with_words(WORDS.into_iter().zip(1..))
+    }
+
+    /// The given words count as well as the numerical digits, a word overriding a digit.
+    ///
+    /// The value of a word may have several digits, like 12 for `twelve`.
+    pub fn with_words<K: AsRef<[u8]>>(words: impl IntoIterator<Item = (K, u64)>) -> Self {
+        let digits = (0..10).map(|d| (d.to_string().into_bytes(), d));
+        let words = words.into_iter().map(|(w, v)| (w.as_ref().to_vec(), v));
+        Self {
+            automaton: Arc::new(Automaton::new(digits.chain(words))),
+        }
+    }
+
+    /// Reads the words counting as well as the numerical digits, one per line followed by its
+    /// value like `zwölf 12`. Empty lines are skipped.
+    pub fn load(input: impl BufRead) -> Result<Self, ParseError> {
+        let mut words = Vec::new();
+        for line in lines(input) {
+            let (n, line) = line?;
+            let mut tokens = line.split_whitespace();
+            let Some(word) = tokens.next() else {
+                continue;
+            };
+            let value = tokens
+                .next()
+                .ok_or_else(|| ParseError::end(format!("the value of `{word}`")))
+                .and_then(|v| parse_token(&line, v, "a number"));
+            if let Some(extra) = tokens.next() {
+                return Err(ParseError::in_text(&line, extra, "the end of the line").on_line(n));
+            }
+            words.push((word.to_string(), value.map_err(|e| e.on_line(n))?));
+        }
+        Ok(Self::with_words(words))
+    }
+
+    /// The first and last digit of the line, `None` if there is none.
+    pub fn line(&self, line: &str) -> Option<Calibration> {
+        let digit = |m: Match<u64>| Digit {
+            value: *m.value,
+            start: m.start,
+            end: m.end,
+        };
+        // Matches are ordered by their end, but a longer word may start before a shorter one
+        // inside the word before it.
+        let mut matches = self.automaton.find_iter(line).map(digit);
+        let first = matches.next()?;
+        let (first, last) = matches.fold((first, first), |(first, last), d| {
+            (
+                if d.start < first.start { d } else { first },
+                if d.start > last.start { d } else { last },
+            )
+        });
+        Some(Calibration { first, last })
+    }
+
+    /// Sums the calibration values of the lines, 0 for those without a digit, scanning chunks of
+    /// lines on the shared pool.
+    pub fn sum(&self, lines: &Arc<[String]>) -> u64 {
+        let chunks: Vec<_> = (0..lines.len()).step_by(CHUNK).collect();
+        let lines = Arc::clone(lines);
+        let calibrator = self.clone();
+        let sums = pool::shared().map(chunks, move |start| {
+            lines[start..lines.len().min(start + CHUNK)]
+                .iter()
+                .filter_map(|line| calibrator.line(line))
+                .map(|c| c.value())
+                .sum::<u64>()
+        });
+        sums.into_iter().sum()
+    }
+}
+
+#[test]
+fn test_modes() {
+    let line = "xtwone3fourteen";
+    let digits = Calibrator::digits().line(line).unwrap();
+    let span = |d: Digit| (d.value, d.start, d.end);
+    assert_eq!(
+        (span(digits.first), span(digits.last)),
+        ((3, 6, 7), (3, 6, 7))
+    );
+    assert_eq!(digits.value(), 33);
+    let words = Calibrator::words().line(line).unwrap();
+    assert_eq!(
+        (span(words.first), span(words.last)),
+        ((2, 1, 4), (4, 7, 11))
+    );
+    assert_eq!(words.value(), 24);
+    let custom = Calibrator::load(&b"fourteen 14\n\ntwo 2\n"[..]).unwrap();
+    let custom = custom.line(line).unwrap();
+    assert_eq!((custom.first.value, custom.last.value), (2, 14));
+    assert_eq!(custom.value(), 214);
+    assert_eq!(Calibrator::digits().line("none"), None);
+}
+
+#[test]
+fn test_load_errors() {
+    let e = Calibrator::load(&b"one 1\ntwo\n"[..]).unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "line 2: expected the value of `two` but the input ended"
+    );
+    let e = Calibrator::load(&b"one x\n"[..]).unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (1, 5, "x"));
+    let e = Calibrator::load(&b"one 1 2\n"[..]).unwrap_err();
+    assert_eq!(
+        (e.line, e.column, e.expected.as_str()),
+        (1, 7, "the end of the line")
+    );
+}
@@ -0,0 +1,63 @@
+use aoc_core::rng::Rng;
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Generates a calibration document of `size` lines mixing letters, digits and spelled out
+/// digits, every line holding at least one digit.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut document = String::new();
+    for _ in 0..size {
+        let mut line = String::new();
+        let digit = rng.index(4);
+        for i in 0..4 {
+            if i == digit || rng.chance(1, 3) {
+                line.push(char::from(b'1' + rng.index(9) as u8));
+            }
+            if rng.chance(1, 2) {
+                line.push_str(rng.choose::<&str>(&WORDS));
+            }
+            for _ in 0..rng.index(4) {
+                line.push(char::from(b'a' + rng.index(26) as u8));
+            }
+        }
+        document.push_str(&line);
+        document.push('\n');
+    }
+    document
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    // The calibration value of a line, trying every digit and word at every position.
+    fn calibration(line: &str, words: &[&str]) -> u64 {
+        let digits: Vec<u64> = (0..line.len())
+            .filter_map(|i| {
+                let rest = &line[i..];
+                let digit = rest.chars().next()?.to_digit(10).map(u64::from);
+                digit.or_else(|| {
+                    (1..)
+                        .zip(words)
+                        .find(|(_, w)| rest.starts_with(*w))
+                        .map(|(d, _)| d)
+                })
+            })
+            .collect();
+        digits[0] * 10 + digits[digits.len() - 1]
+    }
+
+    for seed in 0..5 {
+        let text = generate(100, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(input.len(), 100);
+        assert!(text.lines().all(|l| l.bytes().any(|b| b.is_ascii_digit())));
+        let sum = |words: &[&str]| text.lines().map(|l| calibration(l, words)).sum::<u64>();
+        assert_eq!(Solution::part1(&input), sum(&[]));
+        assert_eq!(Solution::part2(&input), sum(&WORDS));
+    }
+}
@@ -0,0 +1,55 @@
+pub mod automaton;
+pub mod calibration;
+mod generate;
+pub mod trie;
+use crate::calibration::Calibrator;
+use aoc_core::{ParseError, Solver};
+pub use generate::generate;
+use std::{io::BufRead, sync::Arc};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Arc<[String]>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input).map(|l| l.map(|(_, l)| l)).collect()
+    }
+
+    fn part1(input: &Self::Input) -> u64 {
+        sum_digit_calibrations(input)
+    }
+
+    fn part2(input: &Self::Input) -> u64 {
+        sum_calibrations(input)
+    }
+}
+
+/// Sums the calibration values built from the first and last numerical digit of each line.
+pub fn sum_digit_calibrations(lines: &Arc<[String]>) -> u64 {
+    Calibrator::digits().sum(lines)
+}
+
+/// Sums the calibration values where spelled out digits count as digits as well.
+pub fn sum_calibrations(lines: &Arc<[String]>) -> u64 {
+    Calibrator::words().sum(lines)
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 142);
+}
+
+#[test]
+fn test_sample_words() {
+    let input = Solution::parse_str(include_str!("../test")).unwrap();
+    assert_eq!(Solution::part2(&input), 281);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(1, env!("CARGO_MANIFEST_DIR"));
+}
@@ -1,118 +1,263 @@
-use std::{cell::RefCell, rc::Rc};
-
+/// Builds a [`Trie`] from `key => value` pairs.
 #[macro_export]
 macro_rules! trie {
-    ($($x:expr),*) => {
-        Trie::new(&[$($x),*])
+    ($($key:expr => $value:expr),* $(,)?) => {
+        $crate::trie::Trie::from_iter([$(($key, $value)),*])
     };
 }
 
+/// A map from byte string keys to values that finds the keys a text starts with.
+///
+/// Keys may be prefixes of one another, the empty key being a key like any other. The nodes live
+/// in a single arena and refer to their children by index, so a trie can be shared by threads.
 #[derive(Debug, Clone)]
-pub struct Trie {
-    pub n: Vec<TrieNode>,
+pub struct Trie<V> {
+    /// The nodes, the root first
+    pub(crate) nodes: Vec<TrieNode<V>>,
+    /// The nodes removed from the trie, reused before the arena grows
+    free: Vec<u32>,
+    len: usize,
 }
 
-impl Trie {
-    pub fn new<T>(i: &[T]) -> Self
-    where
-        T: AsRef<str>,
-    {
-        let mut v: Vec<TrieNode> = Vec::new();
-        for s in i {
-            let s = s.as_ref();
-            if let Some(b) = s.bytes().next() {
-                if let Some(n) = v.iter_mut().find(|t| t.v == b) {
-                    n.fill_trie_node(&s.as_bytes()[1..])
-                } else {
-                    let mut node = TrieNode {
-                        v: b,
-                        n: Rc::new(RefCell::new(Vec::new())),
-                    };
-                    node.fill_trie_node(&s.as_bytes()[1..]);
-                    v.push(node);
-                }
-            }
+#[derive(Debug, Clone)]
+pub(crate) struct TrieNode<V> {
+    /// The value of the key ending at this node
+    pub(crate) value: Option<V>,
+    /// The index of the node of the keys continuing with each byte, 0 if there is none
+    pub(crate) children: Box<[u32; 256]>,
+    /// The number of children
+    count: u16,
+}
+
+impl<V> TrieNode<V> {
+    fn new() -> Self {
+        Self {
+            value: None,
+            children: Box::new([0; 256]),
+            count: 0,
         }
-        Self { n: v }
     }
 
-    pub fn try_find(&self, hey_stack: &str) -> Option<u32> {
-        if let Some(c) = hey_stack.chars().next() {
-            if c.is_numeric() {
-                return Some(c.to_digit(10).unwrap());
-            }
+    /// The index of the child continuing with `b`, the root never being one.
+    pub(crate) fn child(&self, b: u8) -> Option<u32> {
+        Some(self.children[b as usize]).filter(|c| *c != 0)
+    }
+}
+
+impl<V> Trie<V> {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![TrieNode::new()],
+            free: Vec::new(),
+            len: 0,
         }
-        // there are no numbers with less than three chars
-        if hey_stack.len() < 3 {
-            return None;
+    }
+
+    /// The number of keys.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn child_or_insert(&mut self, node: u32, b: u8) -> u32 {
+        if let Some(child) = self.nodes[node as usize].child(b) {
+            return child;
         }
-        let b = hey_stack.as_bytes();
-        // We found a possible trie branch
-        if let Some(n) = self.n.iter().find(|t| t.v == b[0]) {
-            return n.match_trie_node(&b[1..]);
+        let child = match self.free.pop() {
+            Some(child) => child,
+            None => {
+                self.nodes.push(TrieNode::new());
+                (self.nodes.len() - 1) as u32
+            }
+        };
+        let parent = &mut self.nodes[node as usize];
+        parent.children[b as usize] = child;
+        parent.count += 1;
+        child
+    }
+
+    /// Sets the value of `key` and returns the value it replaced.
+    pub fn insert(&mut self, key: impl AsRef<[u8]>, value: V) -> Option<V> {
+        let node = key
+            .as_ref()
+            .iter()
+            .fold(0, |node, b| self.child_or_insert(node, *b));
+        let replaced = self.nodes[node as usize].value.replace(value);
+        if replaced.is_none() {
+            self.len += 1;
         }
-        None
+        replaced
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct TrieNode {
-    pub v: u8,
-    pub n: Rc<RefCell<Vec<NodeType>>>,
-}
+    /// The node reached by following the bytes of `key`.
+    fn node(&self, key: &[u8]) -> Option<u32> {
+        key.iter()
+            .try_fold(0, |node, b| self.nodes[node as usize].child(*b))
+    }
+
+    pub fn get(&self, key: impl AsRef<[u8]>) -> Option<&V> {
+        self.nodes[self.node(key.as_ref())? as usize].value.as_ref()
+    }
 
-impl TrieNode {
-    fn match_trie_node(&self, b: &[u8]) -> Option<u32> {
-        let x = self.n.borrow();
-        if let Some(n) = x.iter().find(|e| e.is_b(b[0])) {
-            match n {
-                NodeType::Node(n) => n.match_trie_node(&b[1..]),
-                NodeType::Fin { v: _, p } => Some(*p as u32),
+    pub fn contains(&self, key: impl AsRef<[u8]>) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes `key` and returns its value, freeing the nodes no key passes anymore.
+    pub fn remove(&mut self, key: impl AsRef<[u8]>) -> Option<V> {
+        let key = key.as_ref();
+        let mut path = vec![0];
+        for b in key {
+            path.push(self.nodes[*path.last().unwrap() as usize].child(*b)?);
+        }
+        let removed = self.nodes[*path.last().unwrap() as usize].value.take()?;
+        self.len -= 1;
+        for (i, b) in key.iter().enumerate().rev() {
+            let node = &self.nodes[path[i + 1] as usize];
+            if node.value.is_some() || node.count > 0 {
+                break;
             }
-        } else {
-            None
+            self.free.push(path[i + 1]);
+            let parent = &mut self.nodes[path[i] as usize];
+            parent.children[*b as usize] = 0;
+            parent.count -= 1;
         }
+        Some(removed)
     }
 
-    fn fill_trie_node(&mut self, b: &[u8]) {
-        let mut x = self.n.borrow_mut();
-        if let Some(NodeType::Node(n)) = x.iter_mut().find(|e| {
-            let NodeType::Node(n) = e else {
-                panic!("We shall not find a end when constructing a trie")
-            };
-            n.v == b[0]
-        }) {
-            // We found a trie branch with the same prefix as us so we use that.
-            n.fill_trie_node(&b[1..])
+    /// The length and value of the longest key `text` starts with.
+    pub fn longest_prefix(&self, text: impl AsRef<[u8]>) -> Option<(usize, &V)> {
+        let mut node = &self.nodes[0];
+        let mut longest = node.value.as_ref().map(|v| (0, v));
+        for (i, b) in text.as_ref().iter().enumerate() {
+            match node.child(*b) {
+                Some(child) => node = &self.nodes[child as usize],
+                None => break,
+            }
+            if let Some(v) = &node.value {
+                longest = Some((i + 1, v));
+            }
         }
-        if b.len() == 2 {
-            // Our syntax to create the trie is that the last byte is the numerical value
-            x.push(NodeType::Fin {
-                v: b[0],
-                p: (b[1] as char).to_digit(10).unwrap() as u8,
-            });
-        } else {
-            let mut new_node = TrieNode {
-                v: b[0],
-                n: Rc::new(RefCell::new(Vec::new())),
-            };
-            new_node.fill_trie_node(&b[1..]);
-            x.push(NodeType::Node(new_node));
+        longest
+    }
+
+    /// Iterates over the keys starting with `prefix` and their values, ordered by the keys.
+    pub fn iter_prefix(&self, prefix: impl AsRef<[u8]>) -> Iter<'_, V> {
+        let prefix = prefix.as_ref();
+        Iter {
+            trie: self,
+            stack: self
+                .node(prefix)
+                .map(|node| (prefix.to_vec(), node))
+                .into_iter()
+                .collect(),
         }
     }
+
+    /// Iterates over all keys and their values, ordered by the keys.
+    pub fn iter(&self) -> Iter<'_, V> {
+        self.iter_prefix([])
+    }
 }
 
-#[derive(Debug, Clone)]
-pub enum NodeType {
-    Node(TrieNode),
-    Fin { v: u8, p: u8 },
+impl<V> Default for Trie<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: AsRef<[u8]>, V> FromIterator<(K, V)> for Trie<V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for (key, value) in iter {
+            trie.insert(key, value);
+        }
+        trie
+    }
+}
+
+/// The keys of a [`Trie`] and their values, see [`Trie::iter_prefix`].
+pub struct Iter<'a, V> {
+    trie: &'a Trie<V>,
+    /// The nodes left to visit with their keys, the next one on top
+    stack: Vec<(Vec<u8>, u32)>,
 }
 
-impl NodeType {
-    pub fn is_b(&self, b: u8) -> bool {
-        match self {
-            NodeType::Node(n) => n.v == b,
-            NodeType::Fin { v, p: _ } => *v == b,
+impl<'a, V> Iterator for Iter<'a, V> {
+    type Item = (Vec<u8>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((key, node)) = self.stack.pop() {
+            let node = &self.trie.nodes[node as usize];
+            for b in (0..=255).rev() {
+                if let Some(child) = node.child(b) {
+                    let mut key = key.clone();
+                    key.push(b);
+                    self.stack.push((key, child));
+                }
+            }
+            if let Some(v) = &node.value {
+                return Some((key, v));
+            }
         }
+        None
     }
 }
+
+#[test]
+fn test_trie_map() {
+    let mut trie = trie!("one" => 1, "on" => 2, "eight" => 8, "oneight" => 18);
+    assert_eq!(trie.len(), 4);
+    assert_eq!(trie.get("on"), Some(&2));
+    assert_eq!(trie.get("o"), None);
+    assert!(trie.contains("oneight") && !trie.contains("oneigh"));
+    assert_eq!(trie.longest_prefix("oneigh"), Some((3, &1)));
+    assert_eq!(trie.longest_prefix("oneights"), Some((7, &18)));
+    assert_eq!(trie.longest_prefix("nine"), None);
+    let keys: Vec<(Vec<u8>, &i32)> = trie.iter_prefix("on").collect();
+    assert_eq!(
+        keys,
+        [
+            (b"on".to_vec(), &2),
+            (b"one".to_vec(), &1),
+            (b"oneight".to_vec(), &18)
+        ]
+    );
+    assert_eq!(trie.insert("on", 3), Some(2));
+    assert_eq!(trie.remove("one"), Some(1));
+    assert_eq!(trie.remove("one"), None);
+    assert_eq!(trie.longest_prefix("oneigh"), Some((2, &3)));
+    assert_eq!(trie.get("oneight"), Some(&18));
+    assert_eq!(trie.remove("oneight"), Some(18));
+    assert_eq!(
+        trie.iter().map(|(k, _)| k).collect::<Vec<_>>(),
+        [b"eight".to_vec(), b"on".to_vec()]
+    );
+    assert_eq!(trie.len(), 2);
+    // The nodes of "one" and "oneight" past "on" are reused.
+    trie.insert("onyx", 4);
+    assert_eq!(trie.nodes.len(), 13);
+    // The empty key is a prefix of everything.
+    trie.insert("", 0);
+    assert_eq!(trie.longest_prefix("x"), Some((0, &0)));
+    assert_eq!(trie.iter_prefix("x").count(), 0);
+}
+
+#[test]
+fn test_trie_shared() {
+    fn shareable<T: Send + Sync>(_: &T) {}
+    let trie = trie!("one" => 1, "two" => 2);
+    shareable(&trie);
+    let trie = &trie;
+    std::thread::scope(|s| {
+        let found: Vec<_> = ["one", "twos", "three"]
+            .map(|text| s.spawn(move || trie.longest_prefix(text).map(|(_, v)| *v)))
+            .into_iter()
+            .map(|t| t.join().unwrap())
+            .collect();
+        assert_eq!(found, [Some(1), Some(2), None]);
+    });
+}
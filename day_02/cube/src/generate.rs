@@ -0,0 +1,65 @@
+use aoc_core::rng::Rng;
+
+/// Generates `size` games of up to six pulls, each drawing up to 20 cubes of a color.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut games = String::new();
+    for id in 1..=size {
+        let pulls: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let n = rng.range(1..4) as usize;
+                colors[..n]
+                    .iter()
+                    .map(|color| format!("{} {color}", rng.range(1..21)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect();
+        games.push_str(&format!("Game {id}: {}\n", pulls.join("; ")));
+    }
+    games
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let text = generate(100, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(input.games.len(), 100);
+        // The most cubes of every color shown at once in each game.
+        let most: Vec<[u32; 3]> = text
+            .lines()
+            .map(|line| {
+                let mut most = [0; 3];
+                for cubes in line.split(": ").nth(1).unwrap().split([';', ',']) {
+                    let (n, color) = cubes.trim().split_once(' ').unwrap();
+                    let i = ["red", "green", "blue"]
+                        .iter()
+                        .position(|c| *c == color)
+                        .unwrap();
+                    most[i] = most[i].max(n.parse().unwrap());
+                }
+                most
+            })
+            .collect();
+        let possible = (1..)
+            .zip(&most)
+            .filter(|(_, m)| m[0] <= 12 && m[1] <= 13 && m[2] <= 14);
+        assert_eq!(
+            Solution::part1(&input),
+            possible.map(|(id, _)| id).sum::<u32>()
+        );
+        assert_eq!(
+            Solution::part2(&input),
+            most.iter()
+                .map(|m| m[0] * m[1] * m[2])
+                .map(u64::from)
+                .sum::<u64>()
+        );
+    }
+}
@@ -1,32 +1,265 @@
-#[derive(Debug, Clone, Copy, Default)]
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use std::{
+    collections::BTreeMap,
+    fs::File,
+    io::{self, BufRead, BufReader, ErrorKind},
+    path::Path,
+    str::FromStr,
+};
+
+/// Numbers of cubes by their color, like in a pull or in the bag.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Game {
-    pub red: u32,
-    pub green: u32,
-    pub blue: u32,
+    cubes: BTreeMap<String, u32>,
 }
 
 impl Game {
-    pub fn is_plausible(&self, input: &str) -> bool {
-        match input.trim().split_once(' ').unwrap() {
-            (n, "red") => n.parse::<u32>().expect("Valid number") <= self.red,
-            (n, "blue") => n.parse::<u32>().expect("Valid number") <= self.blue,
-            (n, "green") => n.parse::<u32>().expect("Valid number") <= self.green,
-            _ => {
-                panic!("Unknown color");
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The bag of the puzzle, holding 12 red, 13 green and 14 blue cubes.
+    pub fn bag() -> Self {
+        Self::from_iter([("red", 12), ("green", 13), ("blue", 14)])
+    }
+
+    /// Reads a bag from lines like `red = 12`, skipping empty lines and `#` comments.
+    pub fn load(input: impl BufRead) -> Result<Self, ParseError> {
+        let mut bag = Game::new();
+        for line in aoc_core::lines(input) {
+            let (i, raw) = line?;
+            let line = raw.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let parsed = match line.split_once('=') {
+                Some((color, n)) => {
+                    parse_token(line, n.trim(), "a number of cubes").map(|n| (color.trim(), n))
+                }
+                None => Err(ParseError::in_text(line, line, "`<color> = <cubes>`")),
+            };
+            let (color, n) = parsed.map_err(|e| e.within(&raw, line).on_line(i))?;
+            if bag.cubes.contains_key(color) {
+                return Err(ParseError::in_text(&raw, color, "a color given only once").on_line(i));
             }
+            bag.add(color, n);
+        }
+        Ok(bag)
+    }
+
+    /// The number of cubes of the color, 0 for colors the game does not know.
+    pub fn get(&self, color: &str) -> u32 {
+        self.cubes.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn add(&mut self, color: impl Into<String>, n: u32) {
+        *self.cubes.entry(color.into()).or_default() += n;
+    }
+
+    /// The colors and their numbers of cubes, ordered by the color.
+    pub fn colors(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.cubes.iter().map(|(color, n)| (color.as_str(), *n))
+    }
+
+    /// Checks whether the pull could have been drawn from a bag holding this game's cubes.
+    pub fn is_plausible(&self, pull: &Game) -> bool {
+        pull.colors().all(|(color, n)| n <= self.get(color))
+    }
+    pub fn exchange_if_higher(&mut self, pull: &Game) {
+        for (color, n) in pull.colors() {
+            let cubes = self.cubes.entry(color.to_owned()).or_default();
+            *cubes = (*cubes).max(n);
+        }
+    }
+    /// The product of the numbers of cubes of all colors, `None` if it does not fit into a `u64`.
+    pub fn power(&self) -> Option<u64> {
+        self.cubes
+            .values()
+            .try_fold(1u64, |power, n| power.checked_mul(u64::from(*n)))
+    }
+}
+
+impl<C: Into<String>> FromIterator<(C, u32)> for Game {
+    fn from_iter<I: IntoIterator<Item = (C, u32)>>(iter: I) -> Self {
+        let mut game = Game::new();
+        for (color, n) in iter {
+            game.add(color, n);
         }
+        game
     }
-    pub fn exchange_if_higher(&mut self, input: &str) {
-        match input.trim().split_once(' ').unwrap() {
-            (n, "red") => self.red = self.red.max(n.parse::<u32>().expect("Valid number")),
-            (n, "blue") => self.blue = self.blue.max(n.parse::<u32>().expect("Valid number")),
-            (n, "green") => self.green = self.green.max(n.parse::<u32>().expect("Valid number")),
-            _ => {
-                panic!("Unknown color");
+}
+
+/// Parses a single pull like `3 blue, 4 red`.
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut game = Game::default();
+        for cubes in s.split(',') {
+            let cubes = cubes.trim();
+            let Some((n, color)) = cubes.split_once(' ') else {
+                return Err(ParseError::in_text(s, cubes, "an amount and a color"));
+            };
+            let n: u32 = parse_token(s, n, "a number of cubes")?;
+            if color.is_empty() || color.contains(char::is_whitespace) {
+                return Err(ParseError::in_text(s, color, "a single word color"));
             }
+            game.add(color, n);
+        }
+        Ok(game)
+    }
+}
+
+/// Sums the ids of all games whose pulls could have been drawn from the bag.
+pub fn sum_possible(games: &[(u32, Vec<Game>)], bag: &Game) -> u32 {
+    games
+        .iter()
+        .filter(|(_, pulls)| pulls.iter().all(|p| bag.is_plausible(p)))
+        .map(|(id, _)| id)
+        .sum()
+}
+
+/// Sums the power of the minimal set of cubes needed for each game, counting all colors of the
+/// bag even if a game never pulls them. `None` if the sum does not fit into a `u64`.
+pub fn sum_powers(games: &[(u32, Vec<Game>)], bag: &Game) -> Option<u64> {
+    games.iter().try_fold(0u64, |sum, (_, pulls)| {
+        let mut game = Game::from_iter(bag.colors().map(|(color, _)| (color, 0)));
+        for pull in pulls {
+            game.exchange_if_higher(pull)
         }
+        sum.checked_add(game.power()?)
+    })
+}
+
+/// The games of the puzzle and the bag they are played with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Games {
+    /// The id of every game together with its pulls
+    pub games: Vec<(u32, Vec<Game>)>,
+    /// The bag of the puzzle unless the day's `bag.toml` holds another one
+    pub bag: Game,
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Games;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        let games = aoc_core::lines(input)
+            .map(|l| {
+                let (i, l) = l?;
+                parse_game(&l).map_err(|e| e.on_line(i))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Games {
+            games,
+            bag: Game::bag(),
+        })
+    }
+
+    /// Sums the ids of all games that are possible with the cubes in the bag.
+    fn part1(input: &Self::Input) -> u32 {
+        sum_possible(&input.games, &input.bag)
     }
-    pub fn power(&self) -> u32 {
-        self.red * self.green * self.blue
+
+    /// Sums the power of the minimal set of cubes needed for each game.
+    ///
+    /// # Panics
+    /// If the sum does not fit into a `u64`.
+    fn part2(input: &Self::Input) -> u64 {
+        sum_powers(&input.games, &input.bag).expect("The powers add up to a u64")
+    }
+
+    /// Reads the bag from `bag.toml` in `dir` if there is one.
+    fn configure(input: &mut Self::Input, dir: &Path) -> io::Result<()> {
+        match File::open(dir.join("bag.toml")) {
+            Ok(file) => input.bag = Game::load(BufReader::new(file))?,
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        Ok(())
     }
 }
+
+/// Parses a line like `Game 1: 3 blue, 4 red; 1 red, 2 green`.
+fn parse_game(line: &str) -> Result<(u32, Vec<Game>), ParseError> {
+    let Some((game, pulls)) = line.split_once(':') else {
+        return Err(ParseError::in_text(line, line, "`Game <id>: <pulls>`"));
+    };
+    let id = match game.split_once(' ') {
+        Some(("Game", id)) => parse_token(line, id, "a game id")?,
+        _ => return Err(ParseError::in_text(line, game, "`Game <id>`")),
+    };
+    let pulls = pulls
+        .split(';')
+        .map(|pull| pull.parse().map_err(|e: ParseError| e.within(line, pull)))
+        .collect::<Result<_, _>>()?;
+    Ok((id, pulls))
+}
+
+#[test]
+fn test_parse_error() {
+    let e = parse_game("Game 3: 1 red, purple").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (16, "purple"));
+    let e = parse_game("Game 3: 1 red, 2 light blue").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (18, "light blue"));
+    let e = parse_game("Game x: 1 red").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (6, "x"));
+}
+
+#[test]
+fn test_colors() {
+    let bag = Game::load(include_str!("../bag.toml").as_bytes()).unwrap();
+    assert_eq!(bag, Game::bag());
+    let (_, pulls) = parse_game("Game 1: 2 red, 1 purple; 3 red, 2 green").unwrap();
+    assert!(!bag.is_plausible(&pulls[0]) && bag.is_plausible(&pulls[1]));
+    let mut bag = Game::load(&b"red = 12 # a comment\n\npurple = 1\n"[..]).unwrap();
+    assert!(bag.is_plausible(&pulls[0]));
+    assert_eq!(sum_powers(&[(1, pulls.clone())], &bag), Some(6));
+    bag.add("blue", 1);
+    assert_eq!(sum_powers(&[(1, pulls)], &bag), Some(0));
+    let e = Game::load(&b"red = 1\n  red = 2\n"[..]).unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 3, "red"));
+    let e = Game::load(&b"red = x\n"[..]).unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (1, 7, "x"));
+}
+
+#[test]
+fn test_configure() {
+    let mut input = Solution::parse_str(include_str!("../sample")).unwrap();
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    input.bag = Game::from_iter([("red", 20)]);
+    Solution::configure(&mut input, dir).unwrap();
+    assert_eq!(input.bag, Game::bag());
+    // Days without a `bag.toml` keep the bag of the puzzle.
+    Solution::configure(&mut input, &dir.join("src")).unwrap();
+    assert_eq!(input.bag, Game::bag());
+    input.bag = Game::from_iter([("red", 20), ("green", 20), ("blue", 20)]);
+    assert_eq!(Solution::part1(&input), 15);
+}
+
+#[test]
+fn test_power_overflow() {
+    let (_, pulls) = parse_game("Game 1: 4294967295 red, 4294967295 green, 2 blue").unwrap();
+    let bag = Game::bag();
+    assert_eq!(sum_powers(&[(1, pulls.clone())], &bag), None);
+    let (_, pulls) = parse_game("Game 1: 4294967295 red, 4294967295 green, 1 blue").unwrap();
+    assert_eq!(sum_powers(&[(1, pulls)], &bag), Some(18446744065119617025));
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 8);
+    assert_eq!(Solution::part2(&input), 2286);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(2, env!("CARGO_MANIFEST_DIR"));
+}
@@ -1,41 +1,23 @@
-use cube::Game;
-use std::io::{BufRead, BufReader};
+use aoc_core::{or_exit, Solver, Source};
+use cube::{Game, Solution};
+use std::{fs::File, io::BufReader, path::Path};
 
 fn main() {
-    let input = std::fs::File::open("./input").unwrap();
-    let buffer = BufReader::new(input);
-    let g = Game {
-        red: 12,
-        green: 13,
-        blue: 14,
-    };
-    let sum: u32 = buffer
-        .lines()
-        .filter_map(|l| {
-            let l = l.expect("Valid UTF-8 String");
-            let (id, l) = l
-                .split_once(':')
-                .expect("Game id to be seperated from draws by :");
-            if line_possible(l, &g) {
-                Some(extract_id(id))
-            } else {
-                None
-            }
-        })
-        .sum();
-    println!("{sum}");
-}
-
-fn line_possible(line: &str, g: &Game) -> bool {
-    for pulls in line.split(';') {
-        if !pulls.split(',').all(|p| g.is_plausible(p)) {
-            return false;
+    let mut args = std::env::args().skip(1);
+    let source = Source::from_arg(args.next());
+    let mut games = Solution::read_or_exit(&source);
+    // A file with the cubes in the bag, one `<color> = <cubes>` per line, instead of the day's
+    // `bag.toml`
+    match args.next() {
+        Some(bag) => {
+            let file = or_exit(File::open(&bag), &bag);
+            games.bag = or_exit(Game::load(BufReader::new(file)), &bag);
+        }
+        None => {
+            let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+            or_exit(Solution::configure(&mut games, dir), dir.display());
         }
     }
-    true
-}
-
-fn extract_id(game: &str) -> u32 {
-    let (_, id) = game.split_once(' ').expect("Game {id}");
-    id.parse().unwrap()
+    println!("{}", Solution::part1(&games));
+    println!("{}", Solution::part2(&games));
 }
@@ -0,0 +1,91 @@
+use aoc_core::rng::Rng;
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+
+/// Generates a `size` by `size` engine schematic of numbers with up to three digits and symbols
+/// scattered between them, a third of the symbols being gears. The size is at least 1.
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let mut schematic = String::new();
+    for _ in 0..size {
+        let mut row = Vec::with_capacity(size);
+        while row.len() < size {
+            let free = size - row.len();
+            if rng.chance(1, 6) {
+                let digits = rng.range(1..4).min(free as u64) as u32;
+                let number = rng.range(10u64.pow(digits - 1)..10u64.pow(digits));
+                row.extend(number.to_string().bytes());
+                // Numbers are separated by at least one other character.
+                if row.len() < size {
+                    row.push(b'.');
+                }
+            } else if rng.chance(1, 12) {
+                row.push(if rng.chance(1, 3) {
+                    b'*'
+                } else {
+                    *rng.choose(SYMBOLS)
+                });
+            } else {
+                row.push(b'.');
+            }
+        }
+        schematic.push_str(std::str::from_utf8(&row).unwrap());
+        schematic.push('\n');
+    }
+    schematic
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let text = generate(60, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!((input.width(), input.height()), (60, 60));
+        let rows: Vec<&[u8]> = text.lines().map(str::as_bytes).collect();
+        // Every number with the symbols around it.
+        let mut numbers: Vec<(u32, Vec<(usize, usize)>)> = Vec::new();
+        for (r, row) in rows.iter().enumerate() {
+            let mut c = 0;
+            while c < row.len() {
+                let len = row[c..].iter().take_while(|b| b.is_ascii_digit()).count();
+                if len == 0 {
+                    c += 1;
+                    continue;
+                }
+                let number = std::str::from_utf8(&row[c..c + len])
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                let symbols = (r.saturating_sub(1)..(r + 2).min(rows.len()))
+                    .flat_map(|sr| {
+                        (c.saturating_sub(1)..(c + len + 1).min(row.len())).map(move |sc| (sr, sc))
+                    })
+                    .filter(|&(sr, sc)| !rows[sr][sc].is_ascii_digit() && rows[sr][sc] != b'.')
+                    .collect();
+                numbers.push((number, symbols));
+                c += len;
+            }
+        }
+        let parts = numbers
+            .iter()
+            .filter(|(_, s)| !s.is_empty())
+            .map(|(n, _)| n);
+        assert_eq!(Solution::part1(&input), parts.sum::<u32>());
+        let ratios = text.lines().enumerate().flat_map(|(r, line)| {
+            let numbers = &numbers;
+            line.match_indices('*').filter_map(move |(c, _)| {
+                let adjacent: Vec<u32> = numbers
+                    .iter()
+                    .filter(|(_, s)| s.contains(&(r, c)))
+                    .map(|(n, _)| *n)
+                    .collect();
+                (adjacent.len() == 2).then(|| adjacent[0] * adjacent[1])
+            })
+        });
+        assert_eq!(Solution::part2(&input), ratios.sum::<u32>());
+    }
+}
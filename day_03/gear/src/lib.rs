@@ -0,0 +1,187 @@
+mod generate;
+use aoc_core::{ParseError, Solver};
+pub use generate::generate;
+use grid::{Grid, Position};
+use std::io::BufRead;
+
+/// The SearchResult describes the result given by searching a slice for a number.
+/// Success indicates a number was found that is adjacent to a symbol, the positions of the gears
+/// it is adjacent to are returned as well.
+#[derive(Debug, Clone)]
+enum SearchResult {
+    Success {
+        number: u32,
+        next_index: usize,
+        gears: Vec<Position>,
+    },
+    Failure {
+        next_index: usize,
+    },
+    Eol,
+}
+
+/// A Gear can counts the numbers it is adjacent with if there are more than two adjacent
+/// numbers the Gear is treated as poisioned.
+#[derive(Clone, Copy, Debug)]
+struct Gear {
+    pub numbers: [Option<u32>; 2],
+    pub poisioned: bool,
+}
+
+impl Gear {
+    pub fn new(number: u32) -> Self {
+        Self {
+            numbers: [Some(number), None],
+            poisioned: false,
+        }
+    }
+
+    pub fn add_number(&mut self, number: u32) {
+        if self.numbers[1].is_none() {
+            self.numbers[1] = Some(number)
+        } else {
+            self.poisioned = true
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The engine schematic.
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, Ok)
+    }
+
+    /// Sums all part numbers adjacent to a symbol.
+    fn part1(input: &Self::Input) -> u32 {
+        scan_schematic(input).0
+    }
+
+    /// Sums the gear ratios of all gears adjacent to exactly two part numbers.
+    fn part2(input: &Self::Input) -> u32 {
+        scan_schematic(input).1
+    }
+}
+
+/// Scans the engine schematic and returns the part number sum as well as the gear ratio sum.
+pub fn scan_schematic(lines: &Grid<u8>) -> (u32, u32) {
+    let mut sum = 0;
+    // Tracks the gears adjacent to a number.
+    let mut sym_map: Grid<Option<Gear>> = Grid::filled(lines.width(), lines.height(), None);
+    for i in 0..lines.height() {
+        let mut j = 0;
+        // Look for numbers in line until EOL reached.
+        loop {
+            match search_number(lines, i, j) {
+                SearchResult::Success {
+                    number,
+                    next_index,
+                    gears,
+                } => {
+                    sum += number;
+                    j = next_index;
+                    for s in gears {
+                        if let Some(sym) = &mut sym_map[s] {
+                            sym.add_number(number)
+                        } else {
+                            sym_map[s] = Some(Gear::new(number))
+                        }
+                    }
+                }
+                SearchResult::Failure {
+                    next_index: last_index,
+                } => {
+                    j = last_index;
+                }
+                SearchResult::Eol => break,
+            }
+        }
+    }
+    let sym_val = sym_map.iter().fold(0, |acc, (_, s)| {
+        if let Some(s) = s {
+            if s.poisioned || s.numbers[1].is_none() {
+                return acc;
+            }
+            acc + s.numbers[0].unwrap() * s.numbers[1].unwrap()
+        } else {
+            acc
+        }
+    });
+    (sum, sym_val)
+}
+
+/// Finds next number in line and checks if it is adjacent to any symbol
+/// and returns the appropriate SearchResult.
+fn search_number(data: &Grid<u8>, i: usize, j: usize) -> SearchResult {
+    let Some(start_number) = data.row(i)[j..]
+        .iter()
+        .position(|e| e.is_ascii_digit())
+        .map(|i| i + j)
+    else {
+        return SearchResult::Eol;
+    };
+    let end_number = data.row(i)[start_number..]
+        .iter()
+        .position(|e| !e.is_ascii_digit())
+        .map(|i| i + start_number)
+        .unwrap_or(data.width());
+    // The tiles surrounding the number, including the diagonals.
+    let rows = i.saturating_sub(1)..(i + 2).min(data.height());
+    let columns = start_number.saturating_sub(1)..(end_number + 1).min(data.width());
+    let symbols: Vec<(Position, u8)> = rows
+        .flat_map(|r| columns.clone().map(move |c| Position::new(r, c)))
+        .map(|p| (p, data[p]))
+        .filter(|(_, b)| *b != b'.' && !b.is_ascii_digit())
+        .collect();
+    if symbols.is_empty() {
+        return SearchResult::Failure {
+            next_index: end_number,
+        };
+    }
+
+    // Convert askii number to u32
+    let number = data.row(i)[start_number..end_number]
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |acc, (i, b)| {
+            acc + (*b - 48) as u32 * (10_u32.pow(i as u32))
+        });
+
+    SearchResult::Success {
+        number,
+        next_index: end_number,
+        gears: symbols
+            .into_iter()
+            .filter(|(_, b)| *b == b'*')
+            .map(|(p, _)| p)
+            .collect(),
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 4361);
+    assert_eq!(Solution::part2(&input), 467835);
+}
+
+#[test]
+fn test_edges() {
+    // Symbols next to the last column and numbers next to two gears count as well.
+    let input = Solution::parse_str("12*\n..4\n1*.\n").unwrap();
+    assert_eq!(Solution::part1(&input), 17);
+    assert_eq!(Solution::part2(&input), 12 * 4 + 4);
+    let input = Solution::parse_str(".5\n+.\n").unwrap();
+    assert_eq!(Solution::part1(&input), 5);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(3, env!("CARGO_MANIFEST_DIR"));
+}
@@ -1,4 +1,5 @@
-use std::collections::HashSet;
+use aoc_core::{parse_token, ParseError};
+use std::{collections::HashSet, str::FromStr};
 
 #[derive(Debug, Clone)]
 pub struct Card {
@@ -27,19 +28,28 @@ impl Card {
     }
 }
 
-impl From<String> for Card {
-    fn from(line: String) -> Self {
-        let Some((pulls, winning)) = line.split(':').nth(1).expect("Each Card to have a ':'").split_once('|') else {
-            panic!("Each card needs draws and winning numbers");
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let Some((_, numbers)) = line.split_once(':') else {
+            return Err(ParseError::in_text(line, line, "`Card <id>: <numbers>`"));
+        };
+        let Some((pulls, winning)) = numbers.split_once('|') else {
+            return Err(ParseError::in_text(
+                line,
+                numbers,
+                "pulled and winning numbers seperated by '|'",
+            ));
         };
         let pulls = pulls
             .split_whitespace()
-            .map(|c| c.parse::<u32>().expect("Valid numbers"))
-            .collect();
+            .map(|c| parse_token(line, c, "a number"))
+            .collect::<Result<_, _>>()?;
         let winning = winning
             .split_whitespace()
-            .map(|c| c.parse::<u32>().expect("Valid numbers"))
-            .collect();
-        Card::new(pulls, winning)
+            .map(|c| parse_token(line, c, "a number"))
+            .collect::<Result<_, _>>()?;
+        Ok(Card::new(pulls, winning))
     }
 }
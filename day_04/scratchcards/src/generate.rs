@@ -0,0 +1,72 @@
+use aoc_core::rng::Rng;
+
+/// Generates `size` scratchcards with 10 winning numbers and 25 numbers each.
+///
+/// Most cards win nothing so that the copies won in part 2 stay countable, and like in the real
+/// tables no card wins copies of cards past the end of the table.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut cards = String::new();
+    let mut numbers: Vec<u32> = (1..100).collect();
+    for id in 1..=size {
+        let wins = if rng.chance(7, 10) {
+            0
+        } else {
+            rng.index(5) + 1
+        }
+        .min(size - id);
+        rng.shuffle(&mut numbers);
+        let (winning, rest) = numbers.split_at(10);
+        let mut pulled = winning[..wins].to_vec();
+        pulled.extend(&rest[..25 - wins]);
+        rng.shuffle(&mut pulled);
+        let list = |numbers: &[u32]| {
+            numbers
+                .iter()
+                .map(|n| format!("{n:>2}"))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        cards.push_str(&format!(
+            "Card {id:>3}: {} | {}\n",
+            list(winning),
+            list(&pulled)
+        ));
+    }
+    cards
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let text = generate(1000, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(input.len(), 1000);
+        let wins: Vec<usize> = text
+            .lines()
+            .map(|line| {
+                let (winning, pulled) = line.split_once(": ").unwrap().1.split_once(" | ").unwrap();
+                let winning: Vec<&str> = winning.split_whitespace().collect();
+                pulled
+                    .split_whitespace()
+                    .filter(|n| winning.contains(n))
+                    .count()
+            })
+            .collect();
+        let points = wins.iter().filter(|w| **w > 0).map(|w| 1 << (w - 1));
+        assert_eq!(Solution::part1(&input) as usize, points.sum::<usize>());
+        let mut copies = vec![1; wins.len()];
+        for (card, w) in wins.iter().enumerate() {
+            for won in card + 1..=card + w {
+                copies[won] += copies[card];
+            }
+        }
+        assert_eq!(
+            Solution::part2(&input) as usize,
+            copies.iter().sum::<usize>()
+        );
+    }
+}
@@ -0,0 +1,56 @@
+pub mod card;
+mod generate;
+use crate::card::Card;
+use aoc_core::{ParseError, Solver};
+pub use generate::generate;
+use std::io::BufRead;
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        aoc_core::lines(input)
+            .map(|l| {
+                let (i, line) = l?;
+                line.parse().map_err(|e: ParseError| e.on_line(i))
+            })
+            .collect()
+    }
+
+    /// Sums the points of all scratchcards.
+    fn part1(cards: &Self::Input) -> u32 {
+        cards.iter().map(|c| c.calc_winnings()).sum()
+    }
+
+    /// Counts the scratchcards owned after all won copies have been processed.
+    fn part2(cards: &Self::Input) -> u32 {
+        let mut cards = cards.clone();
+        for i in 0..cards.len() {
+            let (cur, fut) = cards.split_at_mut(i + 1);
+            let current = cur.last().unwrap();
+            let amount = current.amount;
+            let winnings = current.calc_won_scratch();
+            let rest_len = fut.len();
+            fut[..(winnings as usize).min(rest_len)]
+                .iter_mut()
+                .for_each(|c| c.amount += amount);
+        }
+        cards.iter().fold(0, |acc, c| acc + c.amount)
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 13);
+    assert_eq!(Solution::part2(&input), 30);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(4, env!("CARGO_MANIFEST_DIR"));
+}
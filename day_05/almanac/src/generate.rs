@@ -0,0 +1,110 @@
+use aoc_core::rng::Rng;
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// The numbers every map shuffles around.
+const LIMIT: u64 = 1 << 32;
+
+/// Generates an almanac whose maps each consist of `size` mappings and whose seeds line holds a
+/// quarter as many seed ranges.
+///
+/// Like in the real almanacs every map moves the blocks of a partition of `0..2^32` into a new
+/// order, so each number has exactly one preimage.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let seeds: Vec<String> = (0..(size / 4).max(1))
+        .map(|_| {
+            let start = rng.range(0..LIMIT - 1);
+            let len = rng.range(1..(LIMIT - start).min(1 << 28));
+            format!("{start} {len}")
+        })
+        .collect();
+    let mut almanac = format!("seeds: {}\n", seeds.join(" "));
+    for map in MAPS {
+        let mut cuts: Vec<u64> = (1..size.max(1)).map(|_| rng.range(1..LIMIT)).collect();
+        cuts.extend([0, LIMIT]);
+        cuts.sort_unstable();
+        cuts.dedup();
+        let blocks: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+        let mut order: Vec<usize> = (0..blocks.len()).collect();
+        rng.shuffle(&mut order);
+        let mut dest = 0;
+        let mut mappings = Vec::with_capacity(blocks.len());
+        for i in order {
+            let (source, len) = blocks[i];
+            mappings.push(format!("{dest} {source} {len}"));
+            dest += len;
+        }
+        rng.shuffle(&mut mappings);
+        almanac.push_str(&format!("\n{map} map:\n{}\n", mappings.join("\n")));
+    }
+    almanac
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// Follows the mappings `(dest, source, len)` of every map, or back from the last map.
+    fn follow(maps: &[Vec<[u64; 3]>], mut n: u64, back: bool) -> u64 {
+        let mut ordered: Vec<&Vec<[u64; 3]>> = maps.iter().collect();
+        if back {
+            ordered.reverse();
+        }
+        for map in ordered {
+            let (from, to) = if back { (0, 1) } else { (1, 0) };
+            if let Some(m) = map.iter().find(|m| (m[from]..m[from] + m[2]).contains(&n)) {
+                n = n - m[from] + m[to];
+            }
+        }
+        n
+    }
+
+    for seed in 0..5 {
+        let text = generate(40, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(input.states[0].len(), 20);
+        assert!(input.translation.iter().all(|t| t.len() == 40));
+        let mut sections = text.split("\n\n");
+        let numbers = |line: &str| -> Vec<u64> {
+            line.split_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect()
+        };
+        let seeds = numbers(sections.next().unwrap().strip_prefix("seeds:").unwrap());
+        let maps: Vec<Vec<[u64; 3]>> = sections
+            .map(|s| {
+                s.lines()
+                    .skip(1)
+                    .map(|l| numbers(l).try_into().unwrap())
+                    .collect()
+            })
+            .collect();
+        let lowest =
+            |seeds: &mut dyn Iterator<Item = u64>| seeds.map(|s| follow(&maps, s, false)).min();
+        assert_eq!(
+            Some(Solution::part1(&input)),
+            lowest(&mut seeds.iter().copied())
+        );
+        // The lowest location of a range of seeds is the one of its first seed or of a seed that
+        // is mapped to the start of a block by one of the maps.
+        let ranges: Vec<(u64, u64)> = seeds.chunks(2).map(|r| (r[0], r[0] + r[1])).collect();
+        let starts = maps.iter().enumerate().flat_map(|(i, map)| {
+            let maps = &maps[..i];
+            map.iter().map(move |m| follow(maps, m[1], true))
+        });
+        let mut candidates = starts
+            .filter(|s| ranges.iter().any(|r| (r.0..r.1).contains(s)))
+            .chain(ranges.iter().map(|r| r.0));
+        assert_eq!(Some(Solution::part2(&input)), lowest(&mut candidates));
+    }
+}
@@ -1,5 +1,9 @@
+mod generate;
 pub mod range;
-use std::io::{BufRead, BufReader};
+use aoc_core::{interval::Interval, parse_token, ParseError, Solver};
+pub use generate::generate;
+use range::RangeAlmanac;
+use std::io::{self, BufRead, BufReader};
 
 #[derive(Debug, Clone)]
 pub struct Almanac {
@@ -8,51 +12,60 @@ pub struct Almanac {
 }
 
 impl Almanac {
-    pub fn from_file(f: &str) -> Self {
-        let file = std::fs::File::open(f).unwrap();
-        let reader = BufReader::new(file);
+    pub fn from_file(f: &str) -> io::Result<Self> {
+        let file = std::fs::File::open(f)?;
+        Ok(Self::from_reader(BufReader::new(file))?)
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseError> {
         let mut states: [Vec<u64>; 8] = Default::default();
         let mut translation: [Vec<Mapping>; 7] = Default::default();
-        let mut lines = reader.lines();
-        states[0] = lines
-            .next()
-            .unwrap()
-            .unwrap()
+        let mut lines = aoc_core::lines(reader);
+        let (n, seeds) = lines.next().ok_or(ParseError::end("the seeds"))??;
+        let Some(numbers) = seeds.strip_prefix("seeds:") else {
+            return Err(ParseError::in_text(&seeds, &seeds, "`seeds: <numbers>`").on_line(n));
+        };
+        states[0] = numbers
             .split_whitespace()
-            .skip(1)
-            .map(|s| s.parse::<u64>().unwrap())
-            .collect();
-        let mut i = 0;
-        let lines = lines.skip(2);
+            .map(|s| parse_token(&seeds, s, "a seed number"))
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.on_line(n))?;
+        // The index of the map the current mapping belongs to.
+        let mut i: Option<usize> = None;
         for line in lines {
-            let Ok(line) = line else {
-                continue;
-            };
+            let (n, line) = line?;
             if line.is_empty() {
                 continue;
             }
             if !line.as_bytes()[0].is_ascii_digit() {
-                i += 1;
+                let next = i.map_or(0, |i| i + 1);
+                if next == translation.len() {
+                    return Err(ParseError::in_text(&line, &line, "a mapping").on_line(n));
+                }
+                i = Some(next);
                 continue;
             }
+            let Some(i) = i else {
+                return Err(ParseError::in_text(&line, &line, "a map header").on_line(n));
+            };
             let map_data = line
                 .split_whitespace()
-                .map(|i| i.parse::<u64>().unwrap())
-                .collect::<Vec<u64>>();
-            let Some([dest, source_start, range]) = map_data.chunks_exact(3)
-                .next() else {
-                    panic!("Mappings need to consist of three numbers")
-                };
+                .map(|t| parse_token(&line, t, "a number"))
+                .collect::<Result<Vec<u64>, _>>()
+                .map_err(|e| e.on_line(n))?;
+            let [dest, source_start, range] = map_data[..] else {
+                return Err(ParseError::in_text(&line, &line, "three numbers").on_line(n));
+            };
             let m = Mapping {
-                source: (*source_start, *source_start + *range - 1),
-                dest: (*dest, *dest + *range - 1),
+                source: Interval::new(source_start, source_start + range),
+                dest,
             };
             translation[i].push(m)
         }
-        Self {
+        Ok(Self {
             states,
             translation,
-        }
+        })
     }
 
     pub fn process_states(&mut self) {
@@ -60,8 +73,8 @@ impl Almanac {
             let next: Vec<u64> = self.states[i]
                 .iter()
                 .map(|s| {
-                    if let Some(m) = self.translation[i].iter().find(|t| t.source_contains(s)) {
-                        s - m.source.0 + m.dest.0
+                    if let Some(m) = self.translation[i].iter().find(|t| t.source.contains(*s)) {
+                        s - m.source.start() + m.dest
                     } else {
                         *s
                     }
@@ -72,18 +85,47 @@ impl Almanac {
     }
 }
 
+/// Moves the values of the `source` interval to the ones starting at `dest`.
 #[derive(Debug, Clone, Copy)]
 pub struct Mapping {
-    source: (u64, u64),
-    dest: (u64, u64),
+    source: Interval<u64>,
+    dest: u64,
 }
 
-impl Mapping {
-    pub fn source_contains(&self, el: &u64) -> bool {
-        (self.source.0..=self.source.1).contains(el)
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Almanac::from_reader(input)
+    }
+
+    /// Finds the lowest location number of any of the listed seeds.
+    fn part1(almanac: &Self::Input) -> u64 {
+        let mut almanac = almanac.clone();
+        almanac.process_states();
+        *almanac.states.last().unwrap().iter().min().unwrap()
     }
 
-    pub fn has_intersection(&self, el: &(u64, u64)) -> bool {
-        !(el.1 < self.source.0 || el.0 > self.source.1)
+    /// Finds the lowest location number when the seeds line describes ranges of seeds.
+    fn part2(almanac: &Self::Input) -> u64 {
+        let mut almac_range: RangeAlmanac = almanac.clone().into();
+        almac_range.process_state();
+        almac_range.states.last().unwrap().min().unwrap()
     }
 }
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 35);
+    assert_eq!(Solution::part2(&input), 46);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(5, env!("CARGO_MANIFEST_DIR"));
+}
@@ -1,19 +1,11 @@
-use almanac::{range::RangeAlmanac, Almanac};
+use almanac::Solution;
+use aoc_core::{Solver, Source};
 
 fn main() {
+    let source = Source::from_arg(std::env::args().nth(1));
+    let almanac = Solution::read_or_exit(&source);
     // Part 1:
-    let mut almanac = Almanac::from_file("./input");
-    almanac.process_states();
-    println!("{}", almanac.states.last().unwrap().iter().min().unwrap());
+    println!("{}", Solution::part1(&almanac));
     // Part 2:
-    let mut almac_range: RangeAlmanac = almanac.into();
-    almac_range.process_state();
-    let min = almac_range
-        .states
-        .last()
-        .unwrap()
-        .iter()
-        .min_by(|a, b| a.0.cmp(&b.0))
-        .unwrap();
-    println!("{}", min.0)
+    println!("{}", Solution::part2(&almanac))
 }
@@ -0,0 +1,71 @@
+use aoc_core::rng::Rng;
+
+/// Generates a race sheet of `size` races, at most four so that the single race of part 2 fits
+/// into a `u64`.
+///
+/// Every record, as well as the one of the combined race, can be beaten.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    loop {
+        let races: Vec<(u64, u64)> = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = rng.range(7..100);
+                (time, rng.range(1..time * time / 4))
+            })
+            .collect();
+        let join = |numbers: Vec<u64>| -> String { numbers.iter().map(u64::to_string).collect() };
+        let time: u128 = join(races.iter().map(|r| r.0).collect()).parse().unwrap();
+        let distance: u128 = join(races.iter().map(|r| r.1).collect()).parse().unwrap();
+        // The combined record has to be beatable by holding the button for half the time.
+        if distance < (time / 2) * (time - time / 2) {
+            let column = |n: &u64| format!("{n:>6}");
+            return format!(
+                "Time:    {}\nDistance:{}\n",
+                races.iter().map(|r| column(&r.0)).collect::<String>(),
+                races.iter().map(|r| column(&r.1)).collect::<String>()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..20 {
+        let text = generate(4, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let [times, distances]: [Vec<u64>; 2] = text
+            .lines()
+            .map(|l| {
+                l.split_whitespace()
+                    .skip(1)
+                    .map(|n| n.parse().unwrap())
+                    .collect()
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap();
+        let ways = times
+            .iter()
+            .zip(&distances)
+            .map(|(t, d)| (0..=*t).filter(|h| h * (t - h) > *d).count());
+        assert_eq!(Solution::part1(&input) as usize, ways.product::<usize>());
+        // The winning holds of the combined race lie around half of its time, the first one
+        // beating the record and the one before not.
+        let join = |n: &[u64]| -> u64 {
+            n.iter()
+                .map(u64::to_string)
+                .collect::<String>()
+                .parse()
+                .unwrap()
+        };
+        let (time, distance) = (join(&times), join(&distances));
+        let ways = Solution::part2(&input);
+        assert_eq!((time + 1 - ways) % 2, 0);
+        let first = (time + 1 - ways) / 2;
+        assert!(first * (time - first) > distance);
+        assert!(first == 0 || (first - 1) * (time - first + 1) <= distance);
+    }
+}
@@ -0,0 +1,122 @@
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use std::io::BufRead;
+
+/// The race sheet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sheet {
+    /// The time of every race
+    pub times: Vec<u64>,
+    /// The record distance of every race
+    pub distances: Vec<u64>,
+    /// The time and record distance of the single race with kerning removed
+    pub race: (u64, u64),
+}
+
+/// Reads the numbers of a line of the sheet starting with `label`, returning them and the
+/// single number they form without the spaces between them.
+fn sheet_line(
+    lines: &mut impl Iterator<Item = Result<(usize, String), ParseError>>,
+    label: &str,
+) -> Result<(usize, String, Vec<u64>, u64), ParseError> {
+    let (n, line) = lines.next().ok_or(ParseError::end(label))??;
+    let Some(numbers) = line.strip_prefix(label) else {
+        let expected = format!("`{label} <numbers>`");
+        return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+    };
+    let list = numbers
+        .split_whitespace()
+        .map(|number| parse_token(&line, number, "a number"))
+        .collect::<Result<Vec<u64>, _>>()
+        .map_err(|e| e.on_line(n))?;
+    if list.is_empty() {
+        return Err(ParseError::end("a number").at(n, line.len() + 1));
+    }
+    let Ok(joined) = numbers.split_whitespace().collect::<String>().parse() else {
+        let expected = "numbers that fit into 64 bits when joined";
+        return Err(ParseError::in_text(&line, numbers.trim(), expected).on_line(n));
+    };
+    Ok((n, line, list, joined))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Sheet;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(reader: impl BufRead) -> Result<Self::Input, ParseError> {
+        let mut lines = aoc_core::lines(reader);
+        let (_, _, times, time) = sheet_line(&mut lines, "Time:")?;
+        let (n, line, distances, distance) = sheet_line(&mut lines, "Distance:")?;
+        if distances.len() != times.len() {
+            let expected = format!("a record distance for each of the {} races", times.len());
+            return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+        }
+        Ok(Sheet {
+            times,
+            distances,
+            race: (time, distance),
+        })
+    }
+
+    /// Multiplies the number of ways to beat the record of every race.
+    fn part1(sheet: &Self::Input) -> u64 {
+        sheet
+            .times
+            .iter()
+            .zip(&sheet.distances)
+            .map(|(t, d)| ways_to_win(*t, *d))
+            .product()
+    }
+
+    /// Counts the ways to beat the record of the single race with kerning removed.
+    fn part2(sheet: &Self::Input) -> u64 {
+        let (time, dist) = sheet.race;
+        ways_to_win(time, dist)
+    }
+}
+
+/// Counts the milliseconds the button can be held to beat the record `dist` in a race of `time`.
+pub fn ways_to_win(time: u64, dist: u64) -> u64 {
+    // 0 = x² - time * x + dist
+    let (x_1, x_2) = pq(-(time as f64), dist as f64);
+    x_2 - x_1
+}
+
+fn pq(p: f64, q: f64) -> (u64, u64) {
+    let x = -(p / 2.0);
+    // Upper bound pessimistic.
+    let x_2 = (x + ((p / 2.0).powf(2.0) - q).sqrt()).ceil() as u64;
+    // Lower bound inside of range.
+    let x_1 = (x - ((p / 2.0).powf(2.0) - q).sqrt()).floor() as u64 + 1;
+    (x_1, x_2)
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 288);
+    assert_eq!(Solution::part2(&input), 71503);
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Solution::parse_str("Time: 7 15\nDistance: 9\n").unwrap_err();
+    assert_eq!((e.line, e.column), (2, 1));
+    assert!(e.expected.contains("each of the 2 races"));
+    let e = Solution::parse_str("Time: 7 1x\nDistance: 9 40\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (1, 9, "1x"));
+    // Joined the times are more than a u64 holds.
+    let e = Solution::parse_str("Time: 1000000000 10000000000\nDistance: 9 40\n").unwrap_err();
+    assert_eq!((e.line, e.column), (1, 7));
+    let e = Solution::parse_str("Time:\nDistance:\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (1, 6, ""));
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(6, env!("CARGO_MANIFEST_DIR"));
+}
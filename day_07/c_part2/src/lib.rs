@@ -1,4 +1,5 @@
-use std::cmp::Ordering;
+use aoc_core::{parse_token, ParseError};
+use std::{cmp::Ordering, io::BufRead};
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,17 +12,19 @@ pub enum Card {
     A = 14,
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             b @ '2'..='9' => Card::Numeric(b.to_digit(10).unwrap() as u8),
             'T' => Card::T,
             'J' => Card::J,
             'Q' => Card::Q,
             'K' => Card::K,
             'A' => Card::A,
-            _ => panic!("Unknown card {value}"),
-        }
+            _ => return Err(ParseError::new(value, "a card")),
+        })
     }
 }
 
@@ -95,17 +98,66 @@ impl Hand {
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        let p_a = self.power as u8;
+        let p_b = other.power as u8;
+        match p_a.cmp(&p_b) {
+            Ordering::Equal => self.tie_pow.cmp(&other.tie_pow),
+            o => o,
+        }
     }
 }
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let p_a = self.power as u8;
-        let p_b = other.power as u8;
-        match p_a.cmp(&p_b) {
-            Ordering::Equal => Some(self.tie_pow.cmp(&other.tie_pow)),
-            o => Some(o),
+        Some(self.cmp(other))
+    }
+}
+
+/// Reads the hands and their bids.
+pub fn parse_hands(input: impl BufRead) -> Result<Vec<(String, u64)>, ParseError> {
+    let mut hands = Vec::new();
+    for line in aoc_core::lines(input) {
+        let (n, line) = line?;
+        if line.is_empty() {
+            continue;
+        }
+        let Some((hand, bid)) = line.split_once(' ') else {
+            return Err(ParseError::in_text(&line, &line, "a hand and a bid").on_line(n));
+        };
+        if hand.chars().count() != 5 {
+            return Err(ParseError::in_text(&line, hand, "a hand of five cards").on_line(n));
+        }
+        for (column, card) in hand.chars().enumerate() {
+            Card::try_from(card).map_err(|e| e.at(n, column + 1))?;
         }
+        let bid = parse_token(&line, bid, "a bid").map_err(|e| e.on_line(n))?;
+        hands.push((hand.to_string(), bid));
     }
+    Ok(hands)
+}
+
+/// Sums the winnings of all hands ranked by their strength with `J` acting as joker.
+pub fn total_winnings(hands: &[(String, u64)]) -> u64 {
+    let mut hands: Vec<Hand> = hands
+        .iter()
+        .map(|(hand, bid)| {
+            let hand: Vec<Card> = hand
+                .chars()
+                .map(|c| Card::try_from(c).expect("Hands to be validated while parsing"))
+                .collect();
+            Hand::new(&hand[..].try_into().unwrap(), *bid)
+        })
+        .collect();
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, h)| (i as u64 + 1) * h.bid)
+        .sum()
+}
+
+#[test]
+fn test_sample() {
+    let hands = parse_hands(&include_bytes!("../sample")[..]).unwrap();
+    assert_eq!(total_winnings(&hands), 5905);
 }
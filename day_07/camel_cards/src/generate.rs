@@ -0,0 +1,74 @@
+use aoc_core::rng::Rng;
+use std::collections::HashSet;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generates `size` distinct hands with bids below 1000, at most as many as there are different
+/// hands.
+///
+/// Hands often repeat cards so that every kind of hand shows up. Like in the real lists no hand
+/// shows up twice, which would leave their ranks open.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut hands = String::new();
+    let mut drawn = HashSet::new();
+    while drawn.len() < size.min(CARDS.len().pow(5)) {
+        // Drawing from a few cards makes pairs and better hands likely.
+        let kinds: Vec<u8> = (0..rng.range(1..6)).map(|_| *rng.choose(CARDS)).collect();
+        let hand: String = (0..5).map(|_| char::from(*rng.choose(&kinds))).collect();
+        if drawn.insert(hand.clone()) {
+            hands.push_str(&format!("{hand} {}\n", rng.range(1..1000)));
+        }
+    }
+    hands
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// Ranks the hands by their counts of equal cards and then by their cards and adds up the
+    /// bids times the ranks, the jokers joining the most common other card if `jokers`.
+    fn winnings(text: &str, jokers: bool) -> u64 {
+        let order: &[u8] = if jokers { b"J23456789TQKA" } else { CARDS };
+        let mut hands: Vec<(Vec<usize>, Vec<usize>, u64)> = text
+            .lines()
+            .map(|line| {
+                let (hand, bid) = line.split_once(' ').unwrap();
+                let cards: Vec<usize> = hand
+                    .bytes()
+                    .map(|c| order.iter().position(|o| *o == c).unwrap())
+                    .collect();
+                let wild = if jokers {
+                    cards.iter().filter(|c| **c == 0).count()
+                } else {
+                    0
+                };
+                let mut counts: Vec<usize> = (0..order.len())
+                    .filter(|c| !jokers || *c != 0)
+                    .map(|c| cards.iter().filter(|d| **d == c).count())
+                    .filter(|n| *n > 0)
+                    .collect();
+                counts.sort_unstable_by(|a, b| b.cmp(a));
+                match counts.first_mut() {
+                    Some(most) => *most += wild,
+                    None => counts.push(wild),
+                }
+                (counts, cards, bid.parse().unwrap())
+            })
+            .collect();
+        hands.sort();
+        (1..)
+            .zip(&hands)
+            .map(|(rank, (_, _, bid))| rank * bid)
+            .sum()
+    }
+
+    for seed in 0..5 {
+        let text = generate(500, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(Solution::part1(&input), winnings(&text, false));
+        assert_eq!(Solution::part2(&input), winnings(&text, true));
+    }
+}
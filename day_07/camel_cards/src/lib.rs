@@ -1,4 +1,7 @@
-use std::cmp::Ordering;
+mod generate;
+use aoc_core::{ParseError, Solver};
+pub use generate::generate;
+use std::{cmp::Ordering, io::BufRead};
 
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,17 +14,19 @@ pub enum Card {
     A = 14,
 }
 
-impl From<char> for Card {
-    fn from(value: char) -> Self {
-        match value {
+impl TryFrom<char> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Ok(match value {
             b @ '2'..='9' => Card::Numeric(b.to_digit(10).unwrap() as u8),
             'T' => Card::T,
             'J' => Card::J,
             'Q' => Card::Q,
             'K' => Card::K,
             'A' => Card::A,
-            _ => panic!("Unknown card {value}"),
-        }
+            _ => return Err(ParseError::new(value, "a card")),
+        })
     }
 }
 
@@ -90,17 +95,70 @@ impl Hand {
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
+        let p_a = self.power as u8;
+        let p_b = other.power as u8;
+        match p_a.cmp(&p_b) {
+            Ordering::Equal => self.tie_pow.cmp(&other.tie_pow),
+            o => o,
+        }
     }
 }
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        let p_a = self.power as u8;
-        let p_b = other.power as u8;
-        match p_a.cmp(&p_b) {
-            Ordering::Equal => Some(self.tie_pow.cmp(&other.tie_pow)),
-            o => Some(o),
-        }
+        Some(self.cmp(other))
     }
 }
+
+/// Sums the winnings of all hands ranked by their strength.
+pub fn total_winnings(hands: &[(String, u64)]) -> u64 {
+    let mut hands: Vec<Hand> = hands
+        .iter()
+        .map(|(hand, bid)| {
+            let hand: Vec<Card> = hand
+                .chars()
+                .map(|c| Card::try_from(c).expect("Hands to be validated while parsing"))
+                .collect();
+            Hand::new(&hand[..].try_into().unwrap(), *bid)
+        })
+        .collect();
+    hands.sort();
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, h)| (i as u64 + 1) * h.bid)
+        .sum()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The hands as written in the input together with their bids.
+    type Input = Vec<(String, u64)>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        c_part2::parse_hands(input)
+    }
+
+    fn part1(hands: &Self::Input) -> u64 {
+        total_winnings(hands)
+    }
+
+    fn part2(hands: &Self::Input) -> u64 {
+        c_part2::total_winnings(hands)
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 6440);
+    assert_eq!(Solution::part2(&input), 5905);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(7, env!("CARGO_MANIFEST_DIR"));
+}
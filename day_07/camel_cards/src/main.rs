@@ -1,25 +1,9 @@
-use std::io::{BufRead, BufReader};
-
-use camel_cards::{Card, Hand};
+use aoc_core::{Solver, Source};
+use camel_cards::Solution;
 
 fn main() {
-    let input = std::fs::File::open("./input").unwrap();
-    let buffer = BufReader::new(input);
-
-    let mut hands: Vec<Hand> = Vec::new();
-    for line in buffer.lines().map(Result::unwrap) {
-        let Some((hand, bid)) = line.split_once(' ') else {
-            continue;
-        };
-        let hand: Vec<Card> = hand.chars().take(5).map(Card::from).collect();
-        let bid = bid.parse::<u64>().unwrap();
-        hands.push(Hand::new(&hand[..5].try_into().unwrap(), bid));
-    }
-    hands.sort();
-    let s: u64 = hands
-        .iter()
-        .enumerate()
-        .map(|(i, h)| (i as u64 + 1) * h.bid)
-        .sum();
+    let source = Source::from_arg(std::env::args().nth(1));
+    let hands = Solution::read_or_exit(&source);
+    let s = Solution::part1(&hands);
     println!("{s}")
 }
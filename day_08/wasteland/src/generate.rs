@@ -0,0 +1,115 @@
+use aoc_core::{math, rng::Rng};
+
+/// Generates a network of roughly `size` nodes walked by up to six ghosts.
+///
+/// Like in the real networks every ghost runs in a loop whose length is a multiple of the number
+/// of instructions and which passes its `..Z` node only at the end. The ghost starting at `AAA`
+/// loops back to `ZZZ`.
+pub fn generate(size: usize, seed: u64) -> String {
+    network(size, seed).0
+}
+
+/// The network and the steps of the loop of the ghost starting at `AAA` and until all ghosts
+/// meet.
+fn network(size: usize, seed: u64) -> (String, usize, u128) {
+    let mut rng = Rng::new(seed);
+    let ghosts = (size / 100).clamp(1, 6);
+    let budget = (size / ghosts).clamp(4, 2500);
+    let len = (2..=budget.isqrt())
+        .rev()
+        .find(|&n| is_prime(n))
+        .unwrap_or(2);
+    let instructions: String = (0..len)
+        .map(|_| if rng.chance(1, 2) { 'L' } else { 'R' })
+        .collect();
+    let mut names = Names::new(&mut rng);
+    let mut prefixes = Names::prefixes(&mut rng);
+    let mut nodes = Vec::new();
+    let (mut first, mut meeting) = (0, 1);
+    for ghost in 0..ghosts {
+        let steps = len * rng.range(1..(budget / len) as u64 + 1) as usize;
+        // The ghost reaches its `..Z` node after a round of its loop and again every round.
+        if ghost == 0 {
+            first = steps;
+        }
+        meeting = math::lcm(meeting, steps as u128).unwrap();
+        let (start, end) = match ghost {
+            0 => ("AAA".to_owned(), "ZZZ".to_owned()),
+            _ => {
+                let prefix = prefixes.pop().unwrap();
+                (format!("{prefix}A"), format!("{prefix}Z"))
+            }
+        };
+        // The start, the nodes of the loop and its end, which leads back into the loop.
+        let mut path = vec![start];
+        path.extend((1..steps).map(|_| names.next()));
+        path.push(end);
+        for (i, node) in path.iter().enumerate() {
+            let next = &path[if i + 1 < path.len() { i + 1 } else { 1 }];
+            // Taking the other turn never happens, so it may lead anywhere on the loop.
+            let other = &path[rng.index(path.len())];
+            let (left, right) = match instructions.as_bytes()[i % len] {
+                b'L' => (next, other),
+                _ => (other, next),
+            };
+            nodes.push(format!("{node} = ({left}, {right})"));
+        }
+    }
+    rng.shuffle(&mut nodes);
+    let text = format!("{instructions}\n\n{}\n", nodes.join("\n"));
+    (text, first, meeting)
+}
+
+fn is_prime(n: usize) -> bool {
+    n >= 2 && (2..=n.isqrt()).all(|d| !n.is_multiple_of(d))
+}
+
+/// Hands out unique node names that neither end in `A` nor in `Z`.
+struct Names {
+    names: Vec<String>,
+}
+
+impl Names {
+    fn new(rng: &mut Rng) -> Self {
+        let mut names: Vec<String> = Self::pairs()
+            .flat_map(|p| ('B'..='Y').map(move |c| format!("{p}{c}")))
+            .collect();
+        rng.shuffle(&mut names);
+        Self { names }
+    }
+
+    /// The two letters starting the names of the ghosts, leaving out the ones of `AAA` and `ZZZ`.
+    fn prefixes(rng: &mut Rng) -> Vec<String> {
+        let mut prefixes: Vec<String> = Self::pairs().filter(|p| p != "AA" && p != "ZZ").collect();
+        rng.shuffle(&mut prefixes);
+        prefixes
+    }
+
+    fn pairs() -> impl Iterator<Item = String> {
+        ('A'..='Z').flat_map(|a| ('A'..='Z').map(move |b| format!("{a}{b}")))
+    }
+
+    /// # Panics
+    /// If all names are used up.
+    fn next(&mut self) -> String {
+        self.names.pop().expect("At most 16224 nodes")
+    }
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let (text, first, meeting) = network(600, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(input.starting.len(), 6);
+        // The instructions are a prime number of turns, and every loop a multiple of them.
+        let len = text.lines().next().unwrap().len();
+        assert!(is_prime(len));
+        assert_eq!(first % len, 0);
+        assert_eq!(Solution::part1(&input), first);
+        assert_eq!(Solution::part2(&input) as u128, meeting);
+    }
+}
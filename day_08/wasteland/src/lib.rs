@@ -0,0 +1,297 @@
+mod generate;
+use aoc_core::{
+    cycle::{self, Cycle},
+    interner::Interner,
+    math, ParseError, Solver,
+};
+pub use generate::generate;
+use std::io::BufRead;
+
+pub struct Wasteland {
+    pub instructions: String,
+    /// The names of the nodes, their ids index `map`.
+    pub names: Interner,
+    pub starting: Vec<usize>,
+    /// The left and right neighbour of every node.
+    pub map: Vec<(usize, usize)>,
+}
+
+impl Wasteland {
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseError> {
+        let mut lines = aoc_core::lines(reader);
+        let (n, instructions) = lines.next().ok_or(ParseError::end("the instructions"))??;
+        if instructions.is_empty() {
+            return Err(ParseError::end("the instructions").on_line(n));
+        }
+        if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+            return Err(
+                ParseError::in_text(&instructions, &instructions[i..i + 1], "`L` or `R`")
+                    .on_line(n),
+            );
+        }
+        let mut names = Interner::new();
+        let mut map: Vec<Option<(usize, usize)>> = Vec::new();
+        let mut starts = Vec::new();
+        for line in lines {
+            let (n, line) = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let (origin, (l, r)) = parse_node(&line).map_err(|e| e.on_line(n))?;
+            let [origin, l, r] = [origin, l, r].map(|name| names.intern(name) as usize);
+            if names.name(origin as u32).ends_with('A') {
+                starts.push(origin);
+            }
+            map.resize(names.len(), None);
+            map[origin] = Some((l, r));
+        }
+        let map = map
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| {
+                node.ok_or_else(|| ParseError::end(format!("the node `{}`", names.name(i as u32))))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            instructions,
+            names,
+            starting: starts,
+            map,
+        })
+    }
+
+    /// The node reached by following the instruction from `node`.
+    fn step(&self, node: usize, instr: char) -> usize {
+        let (l, r) = self.map[node];
+        if instr == 'L' {
+            l
+        } else {
+            r
+        }
+    }
+
+    /// Counts the steps needed to walk from `AAA` to `ZZZ`, `None` if either node is missing or
+    /// the walk never gets there.
+    pub fn run(&self) -> Option<usize> {
+        let (mut curr, goal) = (
+            self.names.get("AAA")? as usize,
+            self.names.get("ZZZ")? as usize,
+        );
+        // Once every node was visited at every instruction the walk only repeats itself.
+        let states = self.map.len() * self.instructions.len();
+        for (i, instr) in self.instructions.chars().cycle().take(states).enumerate() {
+            curr = self.step(curr, instr);
+            if curr == goal {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    /// Counts the steps until every ghost stands on a `..Z` node at the same time, `None` if
+    /// they never do or the number of steps does not fit into a `usize`.
+    pub fn run_ghost(&self) -> Option<usize> {
+        let ghosts: Vec<Ghost> = self.starting.iter().map(|s| self.ghost(*s)).collect();
+        let Some(latest) = ghosts.iter().max_by_key(|g| g.cycle.prefix) else {
+            return Some(0);
+        };
+        // Until the last ghost reaches its cycle they can only meet on its goals before it.
+        let start = latest.cycle.prefix;
+        let early = latest.hits.iter().take_while(|n| **n < start);
+        if let Some(steps) = early
+            .copied()
+            .find(|n| ghosts.iter().all(|g| g.on_goal(*n)))
+        {
+            return Some(steps);
+        }
+        // From then on every ghost repeats the goals on its cycle, any of which may be the one
+        // they meet on.
+        let mut meetings = vec![(0, 1)];
+        for ghost in &ghosts {
+            let goals: Vec<(u128, u128)> = ghost
+                .hits
+                .iter()
+                .filter(|n| **n >= ghost.cycle.prefix)
+                .map(|n| (*n as u128, ghost.cycle.period as u128))
+                .collect();
+            meetings = meetings
+                .iter()
+                .flat_map(|m| goals.iter().filter_map(|g| math::crt([*m, *g])))
+                .collect();
+        }
+        let start = start as u128;
+        meetings
+            .into_iter()
+            .map(|(steps, period)| steps + start.saturating_sub(steps).div_ceil(period) * period)
+            .min()?
+            .try_into()
+            .ok()
+    }
+
+    /// The cycle of the ghost starting at `start` and the steps after which it stands on a `..Z`
+    /// node.
+    pub fn ghost(&self, start: usize) -> Ghost {
+        let instructions = self.instructions.as_bytes();
+        let step = |&(node, i): &(usize, usize)| {
+            (
+                self.step(node, instructions[i] as char),
+                (i + 1) % instructions.len(),
+            )
+        };
+        // Brent's algorithm detects the cycle within twice the number of states.
+        let states = self.map.len() * instructions.len();
+        let cycle = cycle::find_cycle(&(start, 0), step, 2 * states + 1)
+            .expect("The ghost repeats a state");
+        let mut state = (start, 0);
+        let mut hits = Vec::new();
+        for n in 0..cycle.prefix + cycle.period {
+            if self.names.name(state.0 as u32).ends_with('Z') {
+                hits.push(n);
+            }
+            state = step(&state);
+        }
+        Ghost { cycle, hits }
+    }
+}
+
+/// The walk of a ghost, see [`Wasteland::ghost`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ghost {
+    /// The cycle of the states of the ghost, a node and the index of the next instruction
+    pub cycle: Cycle,
+    /// The steps before the end of the first round of the cycle after which the ghost stands on
+    /// a `..Z` node, in order
+    pub hits: Vec<usize>,
+}
+
+impl Ghost {
+    /// Whether the ghost stands on a `..Z` node after `n` steps.
+    pub fn on_goal(&self, n: usize) -> bool {
+        self.hits.binary_search(&self.cycle.index(n)).is_ok()
+    }
+}
+
+/// Parses a node line like `AAA = (BBB, CCC)`.
+fn parse_node(line: &str) -> Result<(&str, (&str, &str)), ParseError> {
+    let Some((origin, dest)) = line.split_once('=') else {
+        return Err(ParseError::in_text(
+            line,
+            line,
+            "`<node> = (<left>, <right>)`",
+        ));
+    };
+    let dest = dest.trim();
+    let Some((l, r)) = dest
+        .strip_prefix('(')
+        .and_then(|d| d.strip_suffix(')'))
+        .and_then(|d| d.split_once(", "))
+    else {
+        return Err(ParseError::in_text(line, dest, "`(<left>, <right>)`"));
+    };
+    Ok((origin.trim(), (l, r)))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Wasteland;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Reads the map, whose walks of both parts have to reach their goal.
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        let wasteland = Wasteland::from_reader(input)?;
+        if wasteland.run().is_none() {
+            return Err(ParseError::end("a walk from `AAA` to `ZZZ`"));
+        }
+        if wasteland.run_ghost().is_none() {
+            return Err(ParseError::end("ghosts meeting on `..Z` nodes"));
+        }
+        Ok(wasteland)
+    }
+
+    /// Counts the steps needed to walk from `AAA` to `ZZZ`.
+    fn part1(wasteland: &Self::Input) -> usize {
+        wasteland.run().expect("A walk checked when parsing")
+    }
+
+    /// Counts the steps until all ghosts starting on `..A` nodes stand on `..Z` nodes at once.
+    fn part2(wasteland: &Self::Input) -> usize {
+        wasteland
+            .run_ghost()
+            .expect("A meeting checked when parsing")
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str("RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+    assert_eq!(Solution::part1(&input), 2);
+    // The ghosts of the second sample have no `AAA` to start the first walk from.
+    let input = Wasteland::from_reader(include_str!("../sample").as_bytes()).unwrap();
+    assert_eq!(input.run_ghost(), Some(6));
+}
+
+#[test]
+fn test_ghosts() {
+    // The first ghost passes `11Z` twice per round of five steps after one step to its cycle.
+    let input = Wasteland::from_reader(
+        "L\n\n11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n11C = (12Z, 12Z)\n12Z = (11D, 11D)\n11D = (11B, 11B)\n22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22A, 22A)\n".as_bytes(),
+    )
+    .unwrap();
+    let ghost = input.ghost(input.starting[0]);
+    assert_eq!(
+        (ghost.cycle, ghost.hits.as_slice()),
+        (
+            Cycle {
+                prefix: 1,
+                period: 5
+            },
+            &[2, 4][..]
+        )
+    );
+    assert!(ghost.on_goal(9) && !ghost.on_goal(6));
+    assert_eq!(input.run_ghost(), Some(7));
+    // Both ghosts only pass their `..Z` node before they get stuck.
+    let input = Wasteland::from_reader(
+        "LR\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n22A = (22Z, 22Z)\n22Z = (22Z, 22B)\n22B = (22B, 22B)\n".as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(input.run_ghost(), Some(1));
+    // They never meet once the second ghost is stuck next to its `..Z` node.
+    let input = Wasteland::from_reader(
+        "LR\n\n11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n22A = (22B, 22B)\n22B = (22B, 22B)\n22Z = (22Z, 22Z)\n".as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(input.run_ghost(), None);
+}
+
+#[test]
+fn test_undefined_node() {
+    let e = Solution::parse_str("LR\n\nAAA = (BBB, AAA)\n")
+        .err()
+        .unwrap();
+    assert_eq!(e.expected, "the node `BBB`");
+    let e = Solution::parse_str("\n\nAAA = (AAA, AAA)\n").err().unwrap();
+    assert_eq!(e, ParseError::end("the instructions").on_line(1));
+}
+
+#[test]
+fn test_unreachable_goal() {
+    let input = "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+    let e = Solution::parse_str(input).err().unwrap();
+    assert_eq!(e, ParseError::end("a walk from `AAA` to `ZZZ`"));
+    let e = Solution::parse_str("L\n\nAAA = (AAA, AAA)\n")
+        .err()
+        .unwrap();
+    assert_eq!(e, ParseError::end("a walk from `AAA` to `ZZZ`"));
+    // The ghost starting on `AAA` never meets the one stuck on `11A`.
+    let input = "L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n11A = (11A, 11A)\n";
+    let e = Solution::parse_str(input).err().unwrap();
+    assert_eq!(e, ParseError::end("ghosts meeting on `..Z` nodes"));
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(8, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,55 @@
+use aoc_core::rng::Rng;
+
+/// Generates `size` rows of 21 values of polynomials of a degree up to 4.
+///
+/// The coefficients are small enough for the values and all sums to fit into an `i32` for up to
+/// a thousand rows.
+pub fn generate(size: usize, seed: u64) -> String {
+    histories(size, seed).0
+}
+
+/// The rows and the sums of the values of their polynomials after the last and before the first
+/// value.
+fn histories(size: usize, seed: u64) -> (String, [i64; 2]) {
+    let mut rng = Rng::new(seed);
+    let mut rows = String::new();
+    let mut sums = [0; 2];
+    for _ in 0..size {
+        let degree = rng.index(5);
+        let coefficients: Vec<i64> = (0..=degree)
+            .map(|power| {
+                let bound = [30, 20, 8, 4, 2][power];
+                rng.range(0..2 * bound + 1) as i64 - bound as i64
+            })
+            .collect();
+        let value = |x: i64| -> i64 { coefficients.iter().rev().fold(0, |acc, c| acc * x + c) };
+        let values: Vec<String> = (0..21).map(|x| value(x).to_string()).collect();
+        rows.push_str(&values.join(" "));
+        rows.push('\n');
+        sums[0] += value(21);
+        sums[1] += value(-1);
+    }
+    (rows, sums)
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let (text, [next, previous]) = histories(1000, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert!(input.iter().all(|row| row.len() == 21));
+        // The values of a polynomial of a degree up to 4 differ by nothing after five rounds.
+        for row in text.lines() {
+            let mut values: Vec<i64> = row.split(' ').map(|v| v.parse().unwrap()).collect();
+            for _ in 0..5 {
+                values = values.windows(2).map(|w| w[1] - w[0]).collect();
+            }
+            assert!(values.iter().all(|v| *v == 0));
+        }
+        assert_eq!(Solution::part1(&input) as i64, next);
+        assert_eq!(Solution::part2(&input) as i64, previous);
+    }
+}
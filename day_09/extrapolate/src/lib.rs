@@ -0,0 +1,64 @@
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use std::io::BufRead;
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_rows(input)
+    }
+
+    /// Sums the extrapolated next values of all rows.
+    fn part1(number_rows: &Self::Input) -> i32 {
+        number_rows.iter().fold(0, |acc, r| acc + extrapolate(r))
+    }
+
+    /// Sums the extrapolated previous values of all rows.
+    fn part2(number_rows: &Self::Input) -> i32 {
+        number_rows.iter().fold(0, |acc, r| {
+            let mut r = r.clone();
+            r.reverse();
+            acc + extrapolate(&r)
+        })
+    }
+}
+
+fn parse_rows(input: impl BufRead) -> Result<Vec<Vec<i32>>, ParseError> {
+    aoc_core::lines(input)
+        .map(|l| {
+            let (n, l) = l?;
+            l.split_whitespace()
+                .map(|v| parse_token(&l, v, "a number").map_err(|e| e.on_line(n)))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn extrapolate(row: &[i32]) -> i32 {
+    if row.iter().all(|e| *e == 0) {
+        return 0;
+    }
+    let mut dif_row = Vec::with_capacity(row.len());
+    for win in row.windows(2) {
+        dif_row.push(win[1] - win[0]);
+    }
+    row.last().unwrap() + extrapolate(&dif_row)
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 114);
+    assert_eq!(Solution::part2(&input), 2);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(9, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,163 @@
+use aoc_core::rng::Rng;
+use grid::{Direction, Grid, Position};
+
+/// The width and height of the tiles standing for a node of the tree the loop is built around.
+const BLOCK: usize = 3;
+
+/// Generates a map of about `size` by `size` tiles holding a single closed loop of pipes through
+/// the start as well as junk pipes inside and outside of it.
+///
+/// The loop runs around the blocks of tiles of a random tree, each enclosing the tile in the
+/// middle of its block.
+pub fn generate(size: usize, seed: u64) -> String {
+    pipes(size, seed).0
+}
+
+/// The map and the number of tiles of the loop.
+fn pipes(size: usize, seed: u64) -> (String, usize) {
+    let mut rng = Rng::new(seed);
+    let blocks = (size / BLOCK).max(2);
+    let mut links: Grid<u8> = Grid::filled(blocks * BLOCK, blocks * BLOCK, 0);
+    let tree = random_tree(&mut rng, blocks, blocks * blocks / 2 + 1);
+    for &Position { row, col } in &tree.nodes {
+        // Start with a ring around the block.
+        let (top, left) = (row * BLOCK, col * BLOCK);
+        let (bottom, right) = (top + BLOCK - 1, left + BLOCK - 1);
+        for c in left..right {
+            link(&mut links, Position::new(top, c), Direction::Right);
+            link(&mut links, Position::new(bottom, c), Direction::Right);
+        }
+        for r in top..bottom {
+            link(&mut links, Position::new(r, left), Direction::Down);
+            link(&mut links, Position::new(r, right), Direction::Down);
+        }
+    }
+    // Join the rings of linked blocks by swapping two parallel sides for two connections.
+    for &(Position { row, col }, direction) in &tree.edges {
+        let (top, left) = (row * BLOCK, col * BLOCK);
+        let (a, b, across) = match direction {
+            Direction::Right => (
+                Position::new(top, left + BLOCK - 1),
+                Position::new(top + 1, left + BLOCK - 1),
+                Direction::Down,
+            ),
+            _ => (
+                Position::new(top + BLOCK - 1, left),
+                Position::new(top + BLOCK - 1, left + 1),
+                Direction::Right,
+            ),
+        };
+        let c = links.step(a, direction).unwrap();
+        unlink(&mut links, a, across);
+        unlink(&mut links, c, across);
+        link(&mut links, a, direction);
+        link(&mut links, b, direction);
+    }
+
+    let mut map = links.map(|mask| match mask {
+        0 if rng.chance(1, 2) => b'.',
+        0 => *rng.choose(b"|-LJ7F"),
+        _ => pipe(*mask),
+    });
+    let on_loop: Vec<Position> = links.positions().filter(|p| links[*p] != 0).collect();
+    let start = *rng.choose(&on_loop);
+    map[start] = b'S';
+    // Junk next to the start must not look connected to it.
+    for direction in Direction::ALL {
+        if let Some(n) = map.step(start, direction) {
+            if links[start] & bit(direction) == 0
+                && pipe_sides(map[n]) & bit(direction.reverse()) != 0
+            {
+                map[n] = b'.';
+            }
+        }
+    }
+    (map.to_string(), on_loop.len())
+}
+
+/// A random tree of up to `len` nodes in a `side` by `side` lattice and the edges from each
+/// node to its right or lower neighbour.
+struct Tree {
+    nodes: Vec<Position>,
+    edges: Vec<(Position, Direction)>,
+}
+
+fn random_tree(rng: &mut Rng, side: usize, len: usize) -> Tree {
+    let mut seen = Grid::filled(side, side, false);
+    let root = Position::new(rng.index(side), rng.index(side));
+    seen[root] = true;
+    let mut tree = Tree {
+        nodes: vec![root],
+        edges: Vec::new(),
+    };
+    while tree.nodes.len() < len {
+        // Grow the tree from a random node towards an unseen neighbour.
+        let node = *rng.choose(&tree.nodes);
+        let direction = *rng.choose(&Direction::ALL);
+        let Some(next) = seen.step(node, direction).filter(|n| !seen[*n]) else {
+            continue;
+        };
+        seen[next] = true;
+        tree.nodes.push(next);
+        tree.edges.push(match direction {
+            Direction::Right | Direction::Down => (node, direction),
+            _ => (next, direction.reverse()),
+        });
+    }
+    tree
+}
+
+fn bit(direction: Direction) -> u8 {
+    match direction {
+        Direction::Up => 1,
+        Direction::Down => 2,
+        Direction::Left => 4,
+        Direction::Right => 8,
+    }
+}
+
+fn link(links: &mut Grid<u8>, from: Position, direction: Direction) {
+    let to = links.step(from, direction).unwrap();
+    links[from] |= bit(direction);
+    links[to] |= bit(direction.reverse());
+}
+
+fn unlink(links: &mut Grid<u8>, from: Position, direction: Direction) {
+    let to = links.step(from, direction).unwrap();
+    links[from] &= !bit(direction);
+    links[to] &= !bit(direction.reverse());
+}
+
+const PIPES: [(u8, u8); 6] = [
+    (b'|', 1 | 2),
+    (b'-', 4 | 8),
+    (b'L', 1 | 8),
+    (b'J', 1 | 4),
+    (b'7', 2 | 4),
+    (b'F', 2 | 8),
+];
+
+fn pipe(sides: u8) -> u8 {
+    PIPES.iter().find(|(_, s)| *s == sides).expect("A pipe").0
+}
+
+fn pipe_sides(tile: u8) -> u8 {
+    PIPES
+        .iter()
+        .find(|(p, _)| *p == tile)
+        .map_or(0, |(_, s)| *s)
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let (text, len) = pipes(60, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        // Every block of the tree holds one enclosed tile.
+        assert_eq!(Solution::part2(&input), 20 * 20 / 2 + 1);
+        assert_eq!(Solution::part1(&input) as usize, len / 2);
+    }
+}
@@ -0,0 +1,260 @@
+mod generate;
+mod render;
+use aoc_core::{ParseError, Solver};
+pub use generate::generate;
+use grid::{Direction, Grid, Position};
+pub use render::render;
+use std::{collections::VecDeque, io::BufRead};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Grid<u8>;
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    /// Counts the steps to the point of the loop farthest from the start.
+    fn part1(map: &Self::Input) -> u32 {
+        farthest_steps(map)
+    }
+
+    /// Counts the tiles enclosed by the loop.
+    fn part2(map: &Self::Input) -> usize {
+        classify(map)
+            .iter()
+            .filter(|(_, e)| matches!(e, Field::Inside))
+            .count()
+    }
+}
+
+/// Counts the steps along the loop from the start to the pipe farthest from it.
+///
+/// # Panics
+/// If the map has no closed loop through the start, which the parser rejects.
+pub fn farthest_steps(map: &Grid<u8>) -> u32 {
+    let mut map = map.clone();
+    let mut hist = Vec::new();
+    run_maze(&mut map, &mut hist)
+}
+
+/// Classifies every tile as a pipe of the loop, with the start replaced by the pipe it hides,
+/// or as inside or outside of it.
+///
+/// # Panics
+/// If the map has no closed loop through the start, which the parser rejects.
+pub fn classify(map: &Grid<u8>) -> Grid<Field> {
+    let mut map = map.clone();
+    let mut hist = Vec::new();
+    run_maze(&mut map, &mut hist);
+    let mut b_map = Grid::filled(map.width(), map.height(), Field::Inside);
+    for p in hist {
+        b_map[p] = Field::Pipe(map[p]);
+    }
+    filter_non_contained(&mut b_map);
+    ray_test(&mut b_map);
+    b_map
+}
+
+fn parse_map(input: impl BufRead) -> Result<Grid<u8>, ParseError> {
+    let map = Grid::parse(input, |b| match b {
+        b'|' | b'-' | b'L' | b'J' | b'7' | b'F' | b'.' | b'S' => Ok(b),
+        _ => Err(ParseError::new(b as char, "a pipe, ground or the start")),
+    })?;
+    find_loop(&map)?;
+    Ok(map)
+}
+
+/// The tiles of the loop, ending with the start, and the two sides of the start connected to
+/// the adjacent pipes of the loop.
+fn find_loop(map: &Grid<u8>) -> Result<(Vec<Position>, [Direction; 2]), ParseError> {
+    let start = map
+        .position(|e| *e == b'S')
+        .ok_or(ParseError::end("the starting tile `S`"))?;
+    let connects = |pos: Position, d: Direction| {
+        map.step(pos, d).filter(|n| {
+            *n == start || connections(map[*n]).is_some_and(|c| c.contains(&d.reverse()))
+        })
+    };
+    let connected: Vec<Direction> = Direction::ALL
+        .into_iter()
+        .filter(|d| connects(start, *d).is_some())
+        .collect();
+    let [a, b] = connected[..] else {
+        let expected = "a start connected to exactly two pipes";
+        return Err(ParseError::new("S", expected).at(start.row + 1, start.col + 1));
+    };
+    let sides = [a, b];
+    let mut tiles = Vec::new();
+    let (mut pos, mut exit) = (start, sides[0]);
+    loop {
+        let Some(next) = connects(pos, exit) else {
+            let pipe = (map[pos] as char).to_string();
+            return Err(
+                ParseError::new(pipe, "a pipe continuing the loop").at(pos.row + 1, pos.col + 1)
+            );
+        };
+        tiles.push(next);
+        if next == start {
+            return Ok((tiles, sides));
+        }
+        // Leave the pipe on the side it was not entered from.
+        let [a, b] = connections(map[next]).expect("A pipe");
+        exit = if a == exit.reverse() { b } else { a };
+        pos = next;
+    }
+}
+
+fn ray_test(b_map: &mut Grid<Field>) {
+    for i in 0..b_map.height() {
+        let r = b_map.row_mut(i);
+        for j in 0..r.len() {
+            match r[j] {
+                Field::Inside => {
+                    let mut inter = 0;
+                    let mut dir: Option<Direction> = None;
+                    for p in r[..j].iter().filter(|e| matches!(e, Field::Pipe(_))) {
+                        if let Field::Pipe(b) = p {
+                            match b {
+                                b'|' => inter += 1,
+                                b'L' | b'J' => match dir {
+                                    Some(d) => {
+                                        if let Direction::Down = d {
+                                            inter += 1;
+                                            dir = None;
+                                        } else {
+                                            dir = None;
+                                        }
+                                    }
+                                    None => {
+                                        dir = Some(Direction::Up);
+                                    }
+                                },
+                                b'7' | b'F' => match dir {
+                                    Some(d) => {
+                                        if let Direction::Up = d {
+                                            inter += 1;
+                                            dir = None;
+                                        } else {
+                                            dir = None;
+                                        }
+                                    }
+                                    None => dir = Some(Direction::Down),
+                                },
+                                _ => continue,
+                            }
+                        }
+                    }
+                    if inter % 2 == 0 {
+                        r[j] = Field::Outside;
+                    }
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Field {
+    Outside,
+    Inside,
+    Pipe(u8),
+}
+
+fn filter_non_contained(b_map: &mut Grid<Field>) {
+    let (last_row, last_col) = (b_map.height() - 1, b_map.width() - 1);
+    while let Some(p) = b_map.row(0).iter().position(|e| matches!(e, Field::Inside)) {
+        proliferate(Position::new(0, p), b_map);
+    }
+    while let Some(p) = b_map
+        .row(last_row)
+        .iter()
+        .position(|e| matches!(e, Field::Inside))
+    {
+        proliferate(Position::new(last_row, p), b_map);
+    }
+    while let Some(p) =
+        (0..=last_row).find(|i| matches!(b_map[Position::new(*i, 0)], Field::Inside))
+    {
+        proliferate(Position::new(p, 0), b_map);
+    }
+    while let Some(p) =
+        (0..=last_row).find(|i| matches!(b_map[Position::new(*i, last_col)], Field::Inside))
+    {
+        proliferate(Position::new(p, last_col), b_map);
+    }
+}
+
+fn proliferate(seed: Position, b_map: &mut Grid<Field>) {
+    let mut todo = VecDeque::new();
+    todo.push_back(seed);
+    while let Some(p) = todo.pop_front() {
+        if let Field::Inside = b_map[p] {
+            b_map[p] = Field::Outside;
+        } else {
+            continue;
+        }
+        todo.extend(
+            b_map
+                .neighbours(p)
+                .filter(|n| matches!(b_map[*n], Field::Inside)),
+        );
+    }
+}
+
+/// The two sides connected by a pipe, `None` for tiles that are not a pipe.
+fn connections(tile: u8) -> Option<[Direction; 2]> {
+    match tile {
+        b'|' => Some([Direction::Up, Direction::Down]),
+        b'-' => Some([Direction::Left, Direction::Right]),
+        b'L' => Some([Direction::Up, Direction::Right]),
+        b'J' => Some([Direction::Up, Direction::Left]),
+        b'7' => Some([Direction::Down, Direction::Left]),
+        b'F' => Some([Direction::Down, Direction::Right]),
+        _ => None,
+    }
+}
+
+fn run_maze(map: &mut Grid<u8>, hist: &mut Vec<Position>) -> u32 {
+    let (tiles, start) = find_loop(map).expect("A loop checked by the parser");
+    let start_pos = *tiles.last().expect("The start closing the loop");
+    let iteration = tiles.len() as u32;
+    hist.extend(tiles);
+
+    // Replace the start with the pipe it hides
+    map[start_pos] = *b"|-LJ7F"
+        .iter()
+        .find(|p| connections(**p).is_some_and(|c| start.iter().all(|d| c.contains(d))))
+        .unwrap();
+
+    iteration / 2
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...\n").unwrap();
+    assert_eq!(Solution::part1(&input), 8);
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part2(&input), 8);
+}
+
+#[test]
+fn test_parse_error() {
+    // The start connects only to the pipe below it.
+    let e = Solution::parse_str(".....\n.S.7.\n.|.|.\n.L-J.\n.....\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 2, "S"));
+    // The loop through the start breaks at the pipe leading into the ground.
+    let e = Solution::parse_str(".....\n.S-7.\n.|.|.\n.L-..\n.....\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (3, 4, "|"));
+    let e = Solution::parse_str("...\n.F.\n").unwrap_err();
+    assert_eq!(e, ParseError::end("the starting tile `S`"));
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(10, env!("CARGO_MANIFEST_DIR"));
+}
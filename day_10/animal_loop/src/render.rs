@@ -0,0 +1,27 @@
+use crate::{classify, Field};
+use grid::{Canvas, Grid, Rgb};
+
+/// Draws the loop and marks the tiles inside of it with `I` and the ones outside with `O`, like
+/// the puzzle does.
+pub fn render(map: &Grid<u8>) -> Canvas {
+    let mut canvas = Canvas::new(map);
+    for (p, field) in classify(map).iter() {
+        match field {
+            Field::Pipe(_) => canvas.paint([p], Rgb::ORANGE),
+            Field::Inside => canvas.paint([p], Rgb::GREEN).draw([p], b'I'),
+            Field::Outside => canvas.paint([p], Rgb::GRAY).draw([p], b'O'),
+        };
+    }
+    canvas
+}
+
+#[test]
+fn test_render() {
+    use aoc_core::Solver;
+
+    let map = crate::Solution::parse_str("...........\n.S-------7.\n.|F-----7|.\n.||.....||.\n.||.....||.\n.|L-7.F-J|.\n.|..|.|..|.\n.L--J.L--J.\n...........\n").unwrap();
+    assert_eq!(
+        render(&map).text(),
+        "OOOOOOOOOOO\nOS-------7O\nO|F-----7|O\nO||OOOOO||O\nO||OOOOO||O\nO|L-7OF-J|O\nO|II|O|II|O\nOL--JOL--JO\nOOOOOOOOOOO\n"
+    );
+}
@@ -0,0 +1,55 @@
+use aoc_core::rng::Rng;
+use grid::Grid;
+
+/// Generates a `size` by `size` image of the universe with about one galaxy in 25 tiles and
+/// roughly every tenth row and column empty. The size is at least 1.
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(1, 10)).collect();
+    let empty_columns: Vec<bool> = (0..size).map(|_| rng.chance(1, 10)).collect();
+    let mut image = Grid::filled(size, size, b'.');
+    for p in image.positions().collect::<Vec<_>>() {
+        if !empty_rows[p.row] && !empty_columns[p.col] && rng.chance(1, 25) {
+            image[p] = b'#';
+        }
+    }
+    image.to_string()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..3 {
+        let text = generate(30, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let rows: Vec<&[u8]> = text.lines().map(str::as_bytes).collect();
+        let galaxies: Vec<(usize, usize)> = (0..30)
+            .flat_map(|r| (0..30).map(move |c| (r, c)))
+            .filter(|&(r, c)| rows[r][c] == b'#')
+            .collect();
+        let empty_rows: Vec<usize> = (0..30).filter(|r| !rows[*r].contains(&b'#')).collect();
+        let empty_columns: Vec<usize> = (0..30)
+            .filter(|c| rows.iter().all(|r| r[*c] == b'.'))
+            .collect();
+        // The steps between every pair of galaxies, each empty row and column between them
+        // counting `expansion` times.
+        let distances = |expansion: usize| -> usize {
+            let span = |a: usize, b: usize, empty: &[usize]| {
+                let (a, b) = (a.min(b), a.max(b));
+                b - a + empty.iter().filter(|e| (a..b).contains(*e)).count() * (expansion - 1)
+            };
+            let pairs = galaxies
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| galaxies[i + 1..].iter().map(move |b| (a, b)));
+            pairs
+                .map(|(a, b)| span(a.0, b.0, &empty_rows) + span(a.1, b.1, &empty_columns))
+                .sum()
+        };
+        assert_eq!(Solution::part1(&input), distances(2));
+        assert_eq!(Solution::part2(&input), distances(1000000));
+    }
+}
@@ -0,0 +1,144 @@
+mod generate;
+use aoc_core::{pool, search, ParseError, Solver};
+pub use generate::generate;
+use grid::{Grid, Position};
+use std::{io::BufRead, sync::Arc};
+
+pub struct Universe {
+    /// The 'cost' of traveling to the tile horizontaly / vertically
+    weights: Arc<Grid<(usize, usize)>>,
+    /// The galaxies in the universe
+    galaxies: Arc<Vec<Position>>,
+}
+
+impl Universe {
+    pub fn new(weights: Grid<(usize, usize)>, galaxies: Vec<Position>) -> Self {
+        Self {
+            weights: Arc::new(weights),
+            galaxies: Arc::new(galaxies),
+        }
+    }
+
+    /// Reads the image of the universe where every empty row and column is `expansion` times
+    /// as wide.
+    pub fn from_reader(reader: impl BufRead, expansion: usize) -> Result<Self, ParseError> {
+        let image = parse_image(reader)?;
+        Ok(Universe::from_image(&image, expansion))
+    }
+
+    /// Builds the universe from its image where every empty row and column is `expansion`
+    /// times as wide.
+    pub fn from_image(image: &Grid<u8>, expansion: usize) -> Self {
+        let galaxies: Vec<Position> = image
+            .iter()
+            .filter(|(_, b)| **b == b'#')
+            .map(|(p, _)| p)
+            .collect();
+        let empty_rows: Vec<bool> = image.rows().map(|r| !r.contains(&b'#')).collect();
+        let empty_cols: Vec<bool> = image.columns().map(|mut c| c.all(|b| *b != b'#')).collect();
+        let mut weights = image.map(|_| (1, 1));
+        for p in image.positions() {
+            if empty_rows[p.row] {
+                weights[p].1 = expansion;
+            }
+            if empty_cols[p.col] {
+                weights[p].0 += expansion - 1;
+            }
+        }
+        Universe::new(weights, galaxies)
+    }
+
+    /// Sums the distances between all pairs of galaxies, searching from every galaxy in a job
+    /// of the shared pool.
+    pub fn calculate_distance_sum(&self) -> usize {
+        let weights = Arc::clone(&self.weights);
+        let galaxies = Arc::clone(&self.galaxies);
+        let sums = pool::shared().map(self.galaxies.iter().copied(), move |galaxie| {
+            Universe::calc_dist(galaxie, Arc::clone(&weights), Arc::clone(&galaxies))
+                .iter()
+                .sum::<usize>()
+        });
+        sums.iter().sum::<usize>() / 2
+    }
+
+    pub fn calculate_distance_sum_single_thread(&self) -> usize {
+        let mut res = Vec::new();
+        for galaxie in self.galaxies.iter() {
+            let galaxie = *galaxie;
+            let weights = Arc::clone(&self.weights);
+            let galaxies = Arc::clone(&self.galaxies);
+            res.append(&mut Universe::calc_dist(galaxie, weights, galaxies));
+        }
+        res.iter().sum::<usize>() / 2
+    }
+
+    /// The distances from `start` to every galaxy.
+    fn calc_dist(
+        start: Position,
+        weights: Arc<Grid<(usize, usize)>>,
+        galaxies: Arc<Vec<Position>>,
+    ) -> Vec<usize> {
+        let weights = &*weights;
+        let width = weights.width();
+        let index = |p: &Position| p.row * width + p.col;
+        let len = width * weights.height();
+        let dist = search::dijkstra_distances_indexed(start, len, index, |&p| {
+            // Entering a tile costs its horizontal or vertical weight depending on the move.
+            weights.neighbours(p).map(move |n| {
+                let (horizontal, vertical) = weights[n];
+                (n, if n.row == p.row { horizontal } else { vertical })
+            })
+        });
+        galaxies
+            .iter()
+            .map(|g| dist[index(g)].expect("Every tile reachable"))
+            .collect()
+    }
+}
+
+fn parse_image(input: impl BufRead) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, |b| match b {
+        b'.' | b'#' => Ok(b),
+        _ => Err(ParseError::new(b as char, "empty space or a galaxy")),
+    })
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The image of the universe.
+    type Input = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_image(input)
+    }
+
+    /// Sums the shortest paths between all pairs of galaxies with empty space doubled.
+    fn part1(image: &Self::Input) -> usize {
+        Universe::from_image(image, 2).calculate_distance_sum()
+    }
+
+    /// Sums the shortest paths between all pairs of galaxies with empty space grown a million times.
+    fn part2(image: &Self::Input) -> usize {
+        Universe::from_image(image, 1000000).calculate_distance_sum()
+    }
+}
+
+#[test]
+fn test_sample() {
+    let image = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&image), 374);
+    assert_eq!(
+        Universe::from_image(&image, 10).calculate_distance_sum(),
+        1030
+    );
+    let universe = Universe::from_image(&image, 100);
+    assert_eq!(universe.calculate_distance_sum_single_thread(), 8410);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(11, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,116 @@
+use aoc_core::rng::Rng;
+
+/// Generates `size` rows of up to 20 springs with up to six groups of damaged springs each.
+///
+/// The rows are made from an arrangement of the groups, hiding about half of the springs, so
+/// every row has at least one arrangement.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut rows = String::new();
+    for _ in 0..size {
+        let mut groups: Vec<usize> = Vec::new();
+        let mut springs = Vec::new();
+        for _ in 0..rng.range(1..7) {
+            let len = rng.range(1..6) as usize;
+            // Groups are separated by at least one working spring.
+            let gap = rng.index(3) + usize::from(!springs.is_empty());
+            if springs.len() + gap + len > 20 {
+                break;
+            }
+            springs.extend((0..gap).map(|_| b'.'));
+            springs.extend((0..len).map(|_| b'#'));
+            groups.push(len);
+        }
+        springs.extend((0..rng.index(3).min(20 - springs.len())).map(|_| b'.'));
+        for spring in &mut springs {
+            if rng.chance(1, 2) {
+                *spring = b'?';
+            }
+        }
+        let groups: Vec<String> = groups.iter().map(usize::to_string).collect();
+        rows.push_str(&format!(
+            "{} {}\n",
+            String::from_utf8(springs).unwrap(),
+            groups.join(",")
+        ));
+    }
+    rows
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+    use std::collections::HashMap;
+
+    /// Counts the arrangements by trying both kinds of spring for every unknown one.
+    fn brute_force(springs: &mut [u8], groups: &[usize]) -> usize {
+        match springs.iter().position(|s| *s == b'?') {
+            Some(i) => [b'.', b'#']
+                .into_iter()
+                .map(|s| {
+                    springs[i] = s;
+                    let count = brute_force(springs, groups);
+                    springs[i] = b'?';
+                    count
+                })
+                .sum(),
+            None => {
+                let found: Vec<usize> = springs
+                    .split(|s| *s == b'.')
+                    .map(<[u8]>::len)
+                    .filter(|l| *l > 0)
+                    .collect();
+                usize::from(found == groups)
+            }
+        }
+    }
+
+    /// Counts the arrangements placing the first group at every possible position, remembering
+    /// the counts of the rest of the row.
+    fn count(springs: &[u8], groups: &[usize], seen: &mut HashMap<(usize, usize), usize>) -> usize {
+        let Some((&len, rest)) = groups.split_first() else {
+            return usize::from(!springs.contains(&b'#'));
+        };
+        if let Some(n) = seen.get(&(springs.len(), groups.len())) {
+            return *n;
+        }
+        let mut n = 0;
+        for start in 0..springs.len() {
+            let end = start + len;
+            if end <= springs.len()
+                && !springs[start..end].contains(&b'.')
+                && springs.get(end) != Some(&b'#')
+            {
+                n += count(springs.get(end + 1..).unwrap_or_default(), rest, seen);
+            }
+            if springs[start] == b'#' {
+                break;
+            }
+        }
+        seen.insert((springs.len(), groups.len()), n);
+        n
+    }
+
+    for seed in 0..5 {
+        let text = generate(200, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let rows: Vec<(Vec<u8>, Vec<usize>)> = text
+            .lines()
+            .map(|l| {
+                let (springs, groups) = l.split_once(' ').unwrap();
+                (
+                    springs.as_bytes().to_vec(),
+                    groups.split(',').map(|g| g.parse().unwrap()).collect(),
+                )
+            })
+            .collect();
+        let arrangements = rows.iter().map(|(s, g)| brute_force(&mut s.clone(), g));
+        assert_eq!(Solution::part1(&input), arrangements.sum::<usize>());
+        let unfolded = rows.iter().map(|(s, g)| {
+            let springs = [&s[..]; 5].join(&b'?');
+            count(&springs, &g.repeat(5), &mut HashMap::new())
+        });
+        assert_eq!(Solution::part2(&input), unfolded.sum::<usize>());
+    }
+}
@@ -0,0 +1,148 @@
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use grid::Tile;
+use std::{cell::RefCell, collections::HashMap, io::BufRead, rc::Rc};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+enum Field {
+    #[tile(b'?')]
+    Unknown,
+    #[tile(b'.')]
+    Working,
+    #[tile(b'#')]
+    Broken,
+}
+
+#[derive(Debug, Clone)]
+pub struct Springs {
+    springs: Vec<Field>,
+    pattern: Vec<usize>,
+}
+
+impl Springs {
+    fn new(springs: Vec<Field>, pattern: Vec<usize>) -> Self {
+        Self { springs, pattern }
+    }
+
+    /// Unfolds the row by repeating it `folds` times separated by unknown springs.
+    pub fn unfold(&self, folds: usize) -> Self {
+        // Every row ends with a working spring that is not part of the record.
+        let row = &self.springs[..self.springs.len() - 1];
+        let mut springs = vec![row; folds].join(&Field::Unknown);
+        springs.push(Field::Working);
+        Springs::new(springs, self.pattern.repeat(folds))
+    }
+
+    /// Counts the arrangements of broken springs matching the record of the row.
+    pub fn perms(&self) -> usize {
+        Springs::count_perms(
+            &self.springs,
+            &self.pattern,
+            Rc::new(RefCell::new(HashMap::new())),
+        )
+    }
+
+    fn skip_working(springs: &[Field]) -> &[Field] {
+        let s = springs
+            .iter()
+            .position(|f| *f != Field::Working)
+            .unwrap_or(springs.len());
+        &springs[s..]
+    }
+
+    fn calc_next(springs: &[Field], s: usize) -> Vec<&[Field]> {
+        let mut next = Vec::new();
+        for (i, window) in springs.windows(s + 1).enumerate() {
+            if !window[..s].contains(&Field::Working) && window[s] != Field::Broken {
+                next.push(Springs::skip_working(&springs[i + s + 1..]));
+            }
+            if window[0] == Field::Broken {
+                break;
+            }
+        }
+        next
+    }
+
+    fn count_perms(
+        springs: &[Field],
+        rest: &[usize],
+        mem: Rc<RefCell<HashMap<(usize, usize), usize>>>,
+    ) -> usize {
+        let key = (springs.len(), rest.len());
+        if let Some(m) = mem.borrow().get(&key) {
+            return *m;
+        }
+
+        if let [current, rest @ ..] = rest {
+            let r = Springs::calc_next(springs, *current)
+                .iter()
+                .map(|r| Springs::count_perms(r, rest, Rc::clone(&mem)))
+                .sum();
+            mem.borrow_mut().insert(key, r);
+            r
+        } else if springs.contains(&Field::Broken) {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+/// Reads one spring row per line.
+fn parse_springs(input: impl BufRead) -> Result<Vec<Springs>, ParseError> {
+    let mut spring_vec = Vec::new();
+    for line in aoc_core::lines(input) {
+        let (n, line) = line?;
+        let Some((springs, pattern)) = line.split_once(' ') else {
+            return Err(ParseError::in_text(&line, &line, "springs and their pattern").on_line(n));
+        };
+        let springs: Vec<Field> = springs
+            .as_bytes()
+            .iter()
+            .chain(b".")
+            .enumerate()
+            .map(|(j, b)| Field::parse(*b).map_err(|e| e.at(n, j + 1)))
+            .collect::<Result<_, _>>()?;
+        let pattern: Vec<usize> = pattern
+            .split(',')
+            .map(|s| parse_token(&line, s, "a group size").map_err(|e| e.on_line(n)))
+            .collect::<Result<_, _>>()?;
+        spring_vec.push(Springs::new(springs, pattern))
+    }
+    Ok(spring_vec)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Springs>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_springs(input)
+    }
+
+    /// Sums the possible arrangements of all rows.
+    fn part1(springs: &Self::Input) -> usize {
+        springs.iter().map(Springs::perms).sum()
+    }
+
+    /// Sums the possible arrangements of all rows unfolded five times.
+    fn part2(springs: &Self::Input) -> usize {
+        springs.iter().map(|s| s.unfold(5).perms()).sum()
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 21);
+    assert_eq!(Solution::part2(&input), 525152);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(12, env!("CARGO_MANIFEST_DIR"));
+}
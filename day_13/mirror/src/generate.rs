@@ -0,0 +1,123 @@
+use aoc_core::rng::Rng;
+use grid::{Grid, Position};
+
+/// Generates `size` patterns of 5 to 17 rows and columns.
+///
+/// Every pattern reflects perfectly along a single line and along exactly one other line once
+/// its smudge is cleaned.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let patterns: Vec<String> = (0..size).map(|_| pattern(&mut rng).to_string()).collect();
+    patterns.join("\n")
+}
+
+fn pattern(rng: &mut Rng) -> Grid<u8> {
+    loop {
+        let (width, height) = (rng.range(5..18) as usize, rng.range(5..18) as usize);
+        // Mirror the columns left of `2 * column` along `column` and the rows around `row` along
+        // `row`, then smudge a tile of the rows outside of the mirrored columns.
+        let column = rng.range(1..(width as u64 - 1) / 2 + 1) as usize;
+        let row = rng.range(1..height as u64) as usize;
+        let reach = row.min(height - row);
+        let mut pattern = Grid::filled(width, height, b'.');
+        for p in pattern.positions().collect::<Vec<_>>() {
+            let Position { row: r, col: c } = p;
+            let r_source = if r + reach >= row && r < row + reach {
+                r.min(2 * row - 1 - r)
+            } else {
+                r
+            };
+            let c_source = if c < 2 * column {
+                c.min(2 * column - 1 - c)
+            } else {
+                c
+            };
+            let source = Position::new(r_source, c_source);
+            pattern[p] = if source == p {
+                if rng.chance(1, 2) {
+                    b'#'
+                } else {
+                    b'.'
+                }
+            } else {
+                pattern[source]
+            };
+        }
+        let smudge = Position::new(
+            rng.range((row - reach) as u64..(row + reach) as u64) as usize,
+            rng.range(2 * column as u64..width as u64) as usize,
+        );
+        pattern[smudge] = if pattern[smudge] == b'#' { b'.' } else { b'#' };
+        if rng.chance(1, 2) {
+            pattern = pattern.transpose();
+        }
+        // Random tiles may add further reflections by chance.
+        if reflections(&pattern, 0) == 1 && reflections(&pattern, 1) == 1 {
+            return pattern;
+        }
+    }
+}
+
+/// Counts the lines along which the pattern reflects with exactly `smudges` tiles off.
+fn reflections(pattern: &Grid<u8>, smudges: usize) -> usize {
+    [pattern.clone(), pattern.transpose()]
+        .iter()
+        .map(|p| {
+            (1..p.width())
+                .filter(|&line| {
+                    let off: usize = p
+                        .rows()
+                        .map(|r| {
+                            let (left, right) = r.split_at(line);
+                            left.iter().rev().zip(right).filter(|(a, b)| a != b).count()
+                        })
+                        .sum();
+                    off == smudges
+                })
+                .count()
+        })
+        .sum()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// The columns left of and 100 times the rows above the lines along which the pattern
+    /// reflects with exactly `smudges` tiles off.
+    fn summary(pattern: &Grid<u8>, smudges: usize) -> usize {
+        let lines = |p: &Grid<u8>| -> Vec<usize> {
+            (1..p.width())
+                .filter(|&line| {
+                    let off = p.rows().map(|r| {
+                        (0..line.min(p.width() - line))
+                            .filter(|i| r[line - 1 - i] != r[line + i])
+                            .count()
+                    });
+                    off.sum::<usize>() == smudges
+                })
+                .collect()
+        };
+        lines(pattern).iter().sum::<usize>()
+            + 100 * lines(&pattern.transpose()).iter().sum::<usize>()
+    }
+
+    for seed in 0..5 {
+        let text = generate(100, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let patterns: Vec<Grid<u8>> = text
+            .split("\n\n")
+            .map(|p| Grid::parse(p.as_bytes(), Ok).unwrap())
+            .collect();
+        assert_eq!(patterns.len(), 100);
+        assert_eq!(
+            Solution::part1(&input),
+            patterns.iter().map(|p| summary(p, 0)).sum::<usize>()
+        );
+        assert_eq!(
+            Solution::part2(&input),
+            patterns.iter().map(|p| summary(p, 1)).sum::<usize>()
+        );
+    }
+}
@@ -0,0 +1,246 @@
+mod generate;
+use aoc_core::{ParseError, Solver};
+pub use generate::generate;
+use grid::Grid;
+use std::{io::BufRead, slice::Iter};
+
+type Field = Grid<u8>;
+
+#[derive(Clone, Debug)]
+pub struct MirrorField {
+    fields: Vec<Field>,
+}
+
+impl MirrorField {
+    pub fn new(fields: Vec<Field>) -> Self {
+        Self { fields }
+    }
+
+    /// Reads the patterns which are separated by empty lines.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseError> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut start = 1;
+        for line in aoc_core::lines(reader) {
+            let (n, line) = line?;
+            if line.is_empty() {
+                if !field.is_empty() {
+                    fields.push(parse_field(&std::mem::take(&mut field), start)?);
+                }
+                start = n + 1;
+                continue;
+            }
+            field.push_str(&line);
+            field.push('\n');
+        }
+        if !field.is_empty() {
+            fields.push(parse_field(&field, start)?);
+        }
+        Ok(Self::new(fields))
+    }
+
+    pub fn calc_mirror_sums(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|f| MirrorField::calc_mirror(f).expect("A line of reflection"))
+            .sum()
+    }
+
+    pub fn calc_smudge_mirror_sums(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|f| MirrorField::calc_smudge_mirror(f).expect("A line of reflection"))
+            .sum()
+    }
+
+    /// Checks for mirror placements that have no element off in the reflection.
+    pub fn calc_mirror(field: &Field) -> Option<usize> {
+        let mut possible_mirrors = Vec::new();
+        for check in 1..field.width() {
+            let (left, right) = field.row(0).split_at(check);
+            let min = left.len().min(right.len());
+            if right[0..min].iter().eq(left[check - min..].iter().rev()) {
+                possible_mirrors.push(check);
+            }
+        }
+        'c: for candidate in possible_mirrors.drain(0..possible_mirrors.len()) {
+            for r in field.rows().skip(1) {
+                let (left, right) = r.split_at(candidate);
+                let min = left.len().min(right.len());
+                if !right[0..min]
+                    .iter()
+                    .eq(left[candidate - min..].iter().rev())
+                {
+                    continue 'c;
+                }
+            }
+            return Some(candidate);
+        }
+        // No horizontal mirror found
+        for (i, (a, b)) in field.rows().zip(field.rows().skip(1)).enumerate() {
+            if a == b {
+                possible_mirrors.push(i + 1)
+            }
+        }
+        for candidate in possible_mirrors {
+            if reflected_rows(field, candidate).all(|(bottom, top)| bottom == top) {
+                return Some(candidate * 100);
+            }
+        }
+        None
+    }
+
+    /// Checks for mirror placements that have exactly one element off in the reflection.
+    pub fn calc_smudge_mirror(field: &Field) -> Option<usize> {
+        let mut possible_mirrors: Vec<(usize, bool)> = Vec::new();
+        for check in 1..field.width() {
+            let (left, right) = field.row(0).split_at(check);
+            let min = left.len().min(right.len());
+            if let Ok(r) = max_one_off(right[0..min].iter(), left[check - min..].iter(), true) {
+                possible_mirrors.push((check, r));
+            }
+        }
+        'c: for (candidate, mut d) in possible_mirrors.drain(0..possible_mirrors.len()) {
+            for r in field.rows().skip(1) {
+                let (left, right) = r.split_at(candidate);
+                let min = left.len().min(right.len());
+                if let Ok(r) =
+                    max_one_off(right[0..min].iter(), left[candidate - min..].iter(), true)
+                {
+                    if r && d {
+                        continue 'c;
+                    }
+                    d |= r;
+                } else {
+                    continue 'c;
+                }
+            }
+            if d {
+                return Some(candidate);
+            }
+        }
+        // No vertical mirror found
+        for (i, (a, b)) in field.rows().zip(field.rows().skip(1)).enumerate() {
+            if let Ok(r) = max_one_off(a.iter(), b.iter(), false) {
+                possible_mirrors.push((i + 1, r))
+            }
+        }
+        for (candidate, _d) in possible_mirrors.iter().copied() {
+            match reflected_rows(field, candidate).try_fold(0, |acc, (top, bottom)| {
+                if acc > 2 {
+                    return Err(());
+                }
+                max_one_off(top.iter(), bottom.iter(), false).map(|e| acc + e as i32)
+            }) {
+                Ok(i) => {
+                    if i == 0 {
+                        continue;
+                    }
+                    return Some(candidate * 100);
+                }
+                Err(_) => continue,
+            }
+        }
+        None
+    }
+}
+
+/// Pairs every row below the mirror after `candidate` rows with its reflection above it.
+fn reflected_rows(field: &Field, candidate: usize) -> impl Iterator<Item = (&[u8], &[u8])> {
+    let min = candidate.min(field.height() - candidate);
+    (0..min).map(move |k| (field.row(candidate + k), field.row(candidate - 1 - k)))
+}
+
+/// Parses a single pattern starting on line `start` of the input, which has to reflect both
+/// with and without its smudge.
+fn parse_field(field: &str, start: usize) -> Result<Field, ParseError> {
+    let pattern = Grid::parse(field.as_bytes(), |b| match b {
+        b'.' | b'#' => Ok(b),
+        _ => Err(ParseError::new(b as char, "ash or rock")),
+    })
+    .map_err(|mut e| {
+        e.line += start - 1;
+        e
+    })?;
+    let first = field.lines().next().unwrap_or_default();
+    if MirrorField::calc_mirror(&pattern).is_none() {
+        let expected = "a pattern with a line of reflection";
+        return Err(ParseError::new(first, expected).at(start, 1));
+    }
+    if MirrorField::calc_smudge_mirror(&pattern).is_none() {
+        let expected = "a pattern with a line of reflection past a smudge";
+        return Err(ParseError::new(first, expected).at(start, 1));
+    }
+    Ok(pattern)
+}
+
+/// Calculates if two u8 iterables are at most one element off.
+/// If they differ in more elements returns Err.
+/// On success returns if there was A element off.
+fn max_one_off(a: Iter<'_, u8>, b: Iter<'_, u8>, reverse: bool) -> Result<bool, ()> {
+    let mut one_off = false;
+    if !reverse {
+        for (a, b) in a.zip(b) {
+            if a != b {
+                match one_off {
+                    true => return Err(()),
+                    false => one_off = true,
+                }
+            }
+        }
+    } else {
+        for (a, b) in a.zip(b.rev()) {
+            if a != b {
+                match one_off {
+                    true => return Err(()),
+                    false => one_off = true,
+                }
+            }
+        }
+    }
+    Ok(one_off)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = MirrorField;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        MirrorField::from_reader(input)
+    }
+
+    /// Sums the reflection line summaries of all patterns.
+    fn part1(field: &Self::Input) -> usize {
+        field.calc_mirror_sums()
+    }
+
+    /// Sums the reflection line summaries of all patterns after fixing their smudge.
+    fn part2(field: &Self::Input) -> usize {
+        field.calc_smudge_mirror_sums()
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 405);
+    assert_eq!(Solution::part2(&input), 400);
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Solution::parse_str("#").unwrap_err();
+    let expected = "a pattern with a line of reflection";
+    assert_eq!(e, ParseError::new("#", expected).at(1, 1));
+    let e = Solution::parse_str("##\n\n#.\n.#\n").unwrap_err();
+    let expected = "a pattern with a line of reflection past a smudge";
+    assert_eq!(e, ParseError::new("##", expected).at(1, 1));
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(13, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,78 @@
+use aoc_core::rng::Rng;
+use grid::Grid;
+
+/// Generates a `size` by `size` platform covered by a fifth in round and a tenth in cube shaped
+/// rocks. The size is at least 1.
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let platform = Grid::filled(size, size, b'.').map(|_| match rng.index(10) {
+        0 | 1 => b'O',
+        2 => b'#',
+        _ => b'.',
+    });
+    platform.to_string()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+    use grid::Direction;
+    use std::collections::HashMap;
+
+    /// Rolls the round rocks north as far as they go, one tile at a time.
+    fn tilt(platform: &mut Grid<u8>) {
+        let mut moved = true;
+        while moved {
+            moved = false;
+            for p in platform.positions().collect::<Vec<_>>() {
+                let Some(up) = p.step(Direction::Up) else {
+                    continue;
+                };
+                if platform[p] == b'O' && platform[up] == b'.' {
+                    (platform[p], platform[up]) = (b'.', b'O');
+                    moved = true;
+                }
+            }
+        }
+    }
+
+    /// The rows from each round rock to the bottom of the platform, itself included.
+    fn load(platform: &Grid<u8>) -> usize {
+        platform
+            .iter()
+            .filter(|(_, t)| **t == b'O')
+            .map(|(p, _)| platform.height() - p.row)
+            .sum()
+    }
+
+    for seed in 0..5 {
+        let text = generate(30, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let mut platform = Grid::parse(text.as_bytes(), Ok).unwrap();
+        let mut tilted = platform.clone();
+        tilt(&mut tilted);
+        assert_eq!(Solution::part1(&input), load(&tilted));
+        // Spin until a platform repeats, then skip the remaining rounds of the cycle.
+        let mut seen = HashMap::new();
+        let mut spins = 0;
+        let target = 1000000000;
+        while spins < target {
+            if let Some(start) = seen.insert(platform.clone(), spins) {
+                let period = spins - start;
+                spins += (target - spins) / period * period;
+                seen.clear();
+                if spins == target {
+                    break;
+                }
+            }
+            for _ in 0..4 {
+                tilt(&mut platform);
+                platform = platform.rotate_clockwise();
+            }
+            spins += 1;
+        }
+        assert_eq!(Solution::part2(&input), load(&platform));
+    }
+}
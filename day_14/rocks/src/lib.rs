@@ -0,0 +1,216 @@
+mod generate;
+use aoc_core::{cycle, ParseError, Solver};
+pub use generate::generate;
+use grid::{Direction, Grid, Position};
+use std::io::BufRead;
+
+/// The platform with its movable round rocks and fixed cube shaped rocks.
+#[derive(Clone, Debug)]
+pub struct Platform {
+    rocks: Vec<Position>,
+    /// The cube shaped rocks of every row
+    r_block: Vec<Vec<usize>>,
+    /// The cube shaped rocks of every column
+    c_block: Vec<Vec<usize>>,
+    cols: usize,
+    rows: usize,
+}
+
+impl Platform {
+    /// The positions of the round rocks before the platform is tilted.
+    pub fn rocks(&self) -> &[Position] {
+        &self.rocks
+    }
+
+    /// The load the round rocks at the given positions put on the north support beams.
+    pub fn load(&self, rocks: &[Position]) -> usize {
+        rocks.iter().fold(0, |acc, r| acc + (self.rows - r.row))
+    }
+
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseError> {
+        let grid = Grid::parse(reader, |b| match b {
+            b'.' | b'O' | b'#' => Ok(b),
+            _ => Err(ParseError::new(
+                b as char,
+                "a round or cube shaped rock or empty space",
+            )),
+        })?;
+        let blocks = |line: &mut dyn Iterator<Item = &u8>| {
+            line.enumerate()
+                .filter(|(_, b)| **b == b'#')
+                .map(|(i, _)| i)
+                .collect()
+        };
+        Ok(Self {
+            rocks: grid
+                .iter()
+                .filter(|(_, b)| **b == b'O')
+                .map(|(p, _)| p)
+                .collect(),
+            r_block: grid.rows().map(|r| blocks(&mut r.iter())).collect(),
+            c_block: grid.columns().map(|mut c| blocks(&mut c)).collect(),
+            cols: grid.width(),
+            rows: grid.height(),
+        })
+    }
+
+    /// Moves the round rocks as far as they roll when tilting the platform towards `rotation`.
+    pub fn tilt(&self, rocks: &mut [Position], rotation: Direction) {
+        let (bound_r, bound_c) = (&self.r_block, &self.c_block);
+        let (rows, cols) = (self.rows, self.cols);
+        if rotation.is_vertical() {
+            rocks.sort_by(|a, b| match a.col.cmp(&b.col) {
+                std::cmp::Ordering::Equal => a.row.cmp(&b.row),
+                o => o,
+            })
+        } else {
+            rocks.sort()
+        }
+
+        match rotation {
+            Direction::Up => {
+                let mut free = 0;
+                let mut current_col_index = 0;
+                'rock: for rock in rocks.iter_mut() {
+                    if current_col_index != rock.col {
+                        free = 0;
+                        current_col_index = rock.col;
+                    }
+                    let blocking = &bound_c[current_col_index];
+                    for block in blocking {
+                        if (free..*block).contains(&rock.row) {
+                            rock.row = free;
+                            free += 1;
+                            continue 'rock;
+                        }
+                        free = free.max(block + 1);
+                    }
+                    if (free..).contains(&rock.row) {
+                        rock.row = free;
+                        free += 1;
+                    }
+                }
+            }
+            Direction::Down => {
+                let mut free = rows - 1;
+                let mut current_col_index = 0;
+                'rock: for rock in rocks.iter_mut().rev() {
+                    if current_col_index != rock.col {
+                        free = rows - 1;
+                        current_col_index = rock.col;
+                    }
+                    let blocking = &bound_c[current_col_index];
+                    for block in blocking.iter().rev() {
+                        if (*block..=free).contains(&rock.row) {
+                            rock.row = free;
+                            free -= 1;
+                            continue 'rock;
+                        }
+                        free = free.min(block.saturating_sub(1));
+                    }
+                    if (..=free).contains(&rock.row) {
+                        rock.row = free;
+                        free = free.saturating_sub(1);
+                    }
+                }
+            }
+            Direction::Left => {
+                let mut free = 0;
+                let mut current_row_index = 0;
+                'rock: for rock in rocks.iter_mut() {
+                    if current_row_index != rock.row {
+                        free = 0;
+                        current_row_index = rock.row;
+                    }
+                    let blocking = &bound_r[current_row_index];
+                    for block in blocking {
+                        if (free..*block).contains(&rock.col) {
+                            rock.col = free;
+                            free += 1;
+                            continue 'rock;
+                        }
+                        free = free.max(block + 1);
+                    }
+                    if (free..).contains(&rock.col) {
+                        rock.col = free;
+                        free += 1;
+                    }
+                }
+            }
+            Direction::Right => {
+                let mut free = cols - 1;
+                let mut current_row_index = 0;
+                'rock: for rock in rocks.iter_mut().rev() {
+                    if current_row_index != rock.row {
+                        free = cols - 1;
+                        current_row_index = rock.row;
+                    }
+                    let blocking = &bound_r[current_row_index];
+                    for block in blocking.iter().rev() {
+                        if (*block..=free).contains(&rock.col) {
+                            rock.col = free;
+                            free -= 1;
+                            continue 'rock;
+                        }
+                        free = free.min(block.saturating_sub(1));
+                    }
+                    if (..=free).contains(&rock.col) {
+                        rock.col = free;
+                        free = free.saturating_sub(1);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Platform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Platform::from_reader(input)
+    }
+
+    /// Calculates the load on the north support beams after tilting the platform north.
+    fn part1(platform: &Self::Input) -> usize {
+        let mut rocks = platform.rocks.clone();
+        platform.tilt(&mut rocks, Direction::Up);
+        platform.load(&rocks)
+    }
+
+    /// Calculates the load on the north support beams after 1000000000 spin cycles.
+    fn part2(platform: &Self::Input) -> usize {
+        let spin = |rocks: &Vec<Position>| {
+            let mut rocks = rocks.clone();
+            for dir in [
+                Direction::Up,
+                Direction::Left,
+                Direction::Down,
+                Direction::Right,
+            ] {
+                platform.tilt(&mut rocks, dir);
+            }
+            // The order of the rocks depends on the last tilt, sorting makes equal states equal.
+            rocks.sort();
+            rocks
+        };
+        let rocks = cycle::nth(platform.rocks.clone(), spin, 1000000000);
+        platform.load(&rocks)
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 136);
+    assert_eq!(Solution::part2(&input), 64);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(14, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,66 @@
+use aoc_core::rng::Rng;
+
+/// Generates an initialization sequence of `size` steps, inserting and removing lenses of about
+/// `size / 4` labels, at least one step.
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let mut labels: Vec<String> = Vec::new();
+    while labels.len() < (size / 4).max(1) {
+        let label: String = (0..rng.range(2..7))
+            .map(|_| char::from(b'a' + rng.index(26) as u8))
+            .collect();
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.chance(1, 3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.range(1..10))
+            }
+        })
+        .collect();
+    steps.join(",") + "\n"
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    let hash = |s: &str| s.bytes().fold(0, |h, b| (h + b as usize) * 17 % 256);
+    for seed in 0..5 {
+        let text = generate(1000, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let steps: Vec<&str> = text.trim_end().split(',').collect();
+        assert_eq!(
+            Solution::part1(&input),
+            steps.iter().map(|s| hash(s)).sum::<usize>()
+        );
+        let mut boxes: Vec<Vec<(&str, usize)>> = vec![Vec::new(); 256];
+        for step in steps {
+            if let Some(label) = step.strip_suffix('-') {
+                boxes[hash(label)].retain(|(l, _)| *l != label);
+            } else {
+                let (label, focal) = step.split_once('=').unwrap();
+                let focal = focal.parse().unwrap();
+                let lenses = &mut boxes[hash(label)];
+                match lenses.iter_mut().find(|(l, _)| *l == label) {
+                    Some(lens) => lens.1 = focal,
+                    None => lenses.push((label, focal)),
+                }
+            }
+        }
+        let power = boxes.iter().enumerate().flat_map(|(b, lenses)| {
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(slot, (_, focal))| (b + 1) * (slot + 1) * focal)
+        });
+        assert_eq!(Solution::part2(&input), power.sum::<usize>());
+    }
+}
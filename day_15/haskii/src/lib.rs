@@ -0,0 +1,127 @@
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use std::{io::BufRead, str::FromStr};
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The initialization sequence.
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        let buffer = read_sequence(input)?;
+        for step in buffer.trim().split(',') {
+            step.parse::<Command>()
+                .map_err(|e| e.within(&buffer, step).on_line(1))?;
+        }
+        Ok(buffer)
+    }
+
+    /// Sums the HASH of every step of the initialization sequence.
+    fn part1(buffer: &Self::Input) -> usize {
+        buffer
+            .trim()
+            .split(',')
+            .map(|seq| hash(seq.as_bytes()))
+            .sum()
+    }
+
+    /// Calculates the focusing power of the lens configuration built by the initialization sequence.
+    fn part2(buffer: &Self::Input) -> usize {
+        let mut map: [Vec<String>; 256] = vec![Vec::new(); 256].try_into().unwrap();
+        for cmd in buffer
+            .trim()
+            .split(',')
+            .map(|s| s.parse().expect("Steps to be validated while parsing"))
+        {
+            match cmd {
+                Command::Insert { label, value } => {
+                    let idx = hash(label.as_bytes());
+                    match map[idx].iter().position(|s| s.starts_with(&label)) {
+                        Some(j) => map[idx][j] = format!("{} {}", label, value),
+                        None => map[idx].push(format!("{} {}", label, value)),
+                    }
+                }
+                Command::Remove { label } => {
+                    let idx = hash(label.as_bytes());
+                    if let Some(j) = map[idx].iter().position(|s| s.starts_with(&label)) {
+                        map[idx].remove(j);
+                    }
+                }
+            }
+        }
+        map.iter().enumerate().fold(0_usize, |acc, (i, bin)| {
+            acc + bin.iter().enumerate().fold(0, |acc, (j, v)| {
+                acc + (i + 1)
+                    * (j + 1)
+                    * v.split_ascii_whitespace()
+                        .last()
+                        .unwrap()
+                        .parse::<usize>()
+                        .unwrap()
+            })
+        })
+    }
+}
+
+fn read_sequence(mut input: impl BufRead) -> Result<String, ParseError> {
+    let mut buffer = String::new();
+    input
+        .read_to_string(&mut buffer)
+        .map_err(|e| ParseError::new(e.to_string(), "UTF-8 text"))?;
+    Ok(buffer)
+}
+
+#[derive(Clone, Debug)]
+pub enum Command {
+    Insert { label: String, value: usize },
+    Remove { label: String },
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((label, value)) = s.split_once('=') {
+            Ok(Self::Insert {
+                label: label.to_string(),
+                value: parse_token(s, value, "a focal length")?,
+            })
+        } else if let Some(label) = s.strip_suffix('-') {
+            Ok(Command::Remove {
+                label: label.to_string(),
+            })
+        } else {
+            Err(ParseError::in_text(
+                s,
+                s,
+                "`<label>=<focal length>` or `<label>-`",
+            ))
+        }
+    }
+}
+
+pub fn hash(seq: &[u8]) -> usize {
+    let mut v = 0;
+    for b in seq {
+        v += *b as usize;
+        v *= 17;
+        v %= 256;
+    }
+    v
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 1320);
+    assert_eq!(Solution::part2(&input), 145);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(15, env!("CARGO_MANIFEST_DIR"));
+}
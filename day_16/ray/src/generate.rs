@@ -0,0 +1,63 @@
+use aoc_core::rng::Rng;
+use grid::Grid;
+
+/// Generates a `size` by `size` cave in which about every tenth tile is a mirror or a splitter.
+/// The size is at least 1.
+pub fn generate(size: usize, seed: u64) -> String {
+    let size = size.max(1);
+    let mut rng = Rng::new(seed);
+    let cave = Grid::filled(size, size, b'.').map(|_| {
+        if rng.chance(1, 10) {
+            *rng.choose(b"|-/\\")
+        } else {
+            b'.'
+        }
+    });
+    cave.to_string()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+    use std::collections::HashSet;
+
+    /// The tiles a beam entering the cave on `start` heading `(dr, dc)` passes.
+    fn energize(cave: &[&[u8]], start: (i64, i64, i64, i64)) -> u64 {
+        let mut seen = HashSet::new();
+        let mut beams = vec![start];
+        while let Some((r, c, dr, dc)) = beams.pop() {
+            let Some(tile) = cave.get(r as usize).and_then(|row| row.get(c as usize)) else {
+                continue;
+            };
+            if !seen.insert((r, c, dr, dc)) {
+                continue;
+            }
+            let turns = match tile {
+                b'/' => vec![(-dc, -dr)],
+                b'\\' => vec![(dc, dr)],
+                b'|' if dc != 0 => vec![(-1, 0), (1, 0)],
+                b'-' if dr != 0 => vec![(0, -1), (0, 1)],
+                _ => vec![(dr, dc)],
+            };
+            beams.extend(turns.into_iter().map(|(dr, dc)| (r + dr, c + dc, dr, dc)));
+        }
+        seen.iter()
+            .map(|(r, c, _, _)| (r, c))
+            .collect::<HashSet<_>>()
+            .len() as u64
+    }
+
+    for seed in 0..5 {
+        let text = generate(30, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let cave: Vec<&[u8]> = text.lines().map(str::as_bytes).collect();
+        assert_eq!(Solution::part1(&input), energize(&cave, (0, 0, 0, 1)));
+        let edges =
+            (0..30).flat_map(|i| [(i, 0, 0, 1), (i, 29, 0, -1), (0, i, 1, 0), (29, i, -1, 0)]);
+        assert_eq!(
+            Solution::part2(&input),
+            edges.map(|s| energize(&cave, s)).max().unwrap()
+        );
+    }
+}
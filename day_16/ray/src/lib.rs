@@ -0,0 +1,176 @@
+mod generate;
+mod render;
+use aoc_core::{pool, ParseError, Solver};
+pub use generate::generate;
+use grid::{Direction, Grid, Position, Tile};
+pub use render::render;
+use std::{collections::VecDeque, io::BufRead, sync::Arc};
+
+#[derive(Clone, Debug)]
+pub struct Cave {
+    pub inner: Grid<CaveElement>,
+}
+
+/// The directions a beam leaves a tile in.
+pub enum CollisionResult {
+    Split(Direction, Direction),
+    Single(Direction),
+}
+
+#[derive(Clone, Copy, Debug, Tile)]
+#[tile(expected = "empty space, a mirror or a splitter")]
+pub enum CaveElement {
+    #[tile(b'.')]
+    Empty,
+    #[tile(b'-')]
+    HorzontalMirror,
+    #[tile(b'|')]
+    VerticalMirror,
+    #[tile(b'\\')]
+    LeftRightMirror,
+    #[tile(b'/')]
+    RightLeftMirror,
+}
+
+impl CaveElement {
+    /// Calculates where a beam entering the tile heading in `dir` continues.
+    pub fn collision(&self, dir: Direction) -> CollisionResult {
+        match (self, dir.is_vertical()) {
+            (CaveElement::HorzontalMirror, true) => {
+                CollisionResult::Split(Direction::Left, Direction::Right)
+            }
+            (CaveElement::VerticalMirror, false) => {
+                CollisionResult::Split(Direction::Down, Direction::Up)
+            }
+            (CaveElement::RightLeftMirror, true) | (CaveElement::LeftRightMirror, false) => {
+                CollisionResult::Single(dir.turn_right())
+            }
+            (CaveElement::RightLeftMirror, false) | (CaveElement::LeftRightMirror, true) => {
+                CollisionResult::Single(dir.turn_left())
+            }
+            _ => CollisionResult::Single(dir),
+        }
+    }
+}
+
+impl Cave {
+    pub fn new(cave: Grid<CaveElement>) -> Self {
+        Self { inner: cave }
+    }
+
+    pub fn height(&self) -> usize {
+        self.inner.height()
+    }
+
+    pub fn width(&self) -> usize {
+        self.inner.width()
+    }
+
+    pub fn start_ray(&self) -> u64 {
+        self.calc_ray_energy(Direction::Right, Position::new(0, 0))
+    }
+
+    /// Finds the most energized tiles of any beam entering from the edge, following every beam
+    /// in a job of the shared pool.
+    pub fn start_rays(self: Arc<Self>) -> u64 {
+        let (height, width) = (self.height(), self.width());
+        let mut beams = Vec::new();
+        for w in 0..width {
+            beams.push((Direction::Down, Position::new(0, w)));
+            beams.push((Direction::Up, Position::new(height - 1, w)));
+        }
+        for h in 0..height {
+            beams.push((Direction::Right, Position::new(h, 0)));
+            beams.push((Direction::Left, Position::new(h, width - 1)));
+        }
+        pool::shared()
+            .map(beams, move |(dir, pos)| self.calc_ray_energy(dir, pos))
+            .into_iter()
+            .max()
+            .unwrap()
+    }
+
+    pub fn start_rays_single(self: Arc<Self>) -> u64 {
+        let mut max = 0;
+        for w in 0..self.width() {
+            max = max.max(self.calc_ray_energy(Direction::Down, Position::new(0, w)));
+            max = max.max(self.calc_ray_energy(Direction::Up, Position::new(self.height() - 1, w)));
+        }
+        for h in 0..self.height() {
+            max = max.max(self.calc_ray_energy(Direction::Right, Position::new(h, 0)));
+            max =
+                max.max(self.calc_ray_energy(Direction::Left, Position::new(h, self.width() - 1)));
+        }
+        max
+    }
+
+    fn calc_ray_energy(&self, direction: Direction, position: Position) -> u64 {
+        let path = self.beams(direction, position);
+        path.iter().filter(|(_, p)| !p.is_empty()).count() as u64
+    }
+
+    /// The directions the beam entering at `position` heading in `direction` passes every tile
+    /// in, none for the tiles it does not energize.
+    pub fn beams(&self, direction: Direction, position: Position) -> Grid<Vec<Direction>> {
+        let mut path: Grid<Vec<Direction>> = self.inner.map(|_| Vec::new());
+        let mut priority = VecDeque::new();
+        priority.push_back((position, direction));
+
+        while let Some((p, d)) = priority.pop_front() {
+            let seen = &mut path[p];
+            if seen.contains(&d) {
+                continue;
+            }
+            seen.push(d);
+            let next = match self.inner[p].collision(d) {
+                CollisionResult::Split(a, b) => [Some(a), Some(b)],
+                CollisionResult::Single(d) => [Some(d), None],
+            };
+            priority.extend(
+                next.into_iter()
+                    .flatten()
+                    .filter_map(|d| Some((self.inner.step(p, d)?, d))),
+            );
+        }
+        path
+    }
+}
+
+fn parse_cave(input: impl BufRead) -> Result<Cave, ParseError> {
+    Grid::parse(input, CaveElement::parse).map(Cave::new)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Arc<Cave>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_cave(input).map(Arc::new)
+    }
+
+    /// Counts the energized tiles when the beam enters the top left corner heading right.
+    fn part1(cave: &Self::Input) -> u64 {
+        cave.start_ray()
+    }
+
+    /// Counts the energized tiles of the beam entering from the edge that energizes the most.
+    fn part2(cave: &Self::Input) -> u64 {
+        Arc::clone(cave).start_rays_single()
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 46);
+    assert_eq!(Solution::part2(&input), 51);
+    assert_eq!(Arc::clone(&input).start_rays(), 51);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(16, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,37 @@
+use crate::{Cave, CaveElement};
+use grid::{Canvas, Direction, Position, Rgb, Tile};
+
+/// Draws the tiles energized by the beam of part one.
+///
+/// Like in the puzzle, empty tiles show the direction of the beam passing them or the number of
+/// beams if there are several.
+pub fn render(cave: &Cave) -> Canvas {
+    let beams = cave.beams(Direction::Right, Position::new(0, 0));
+    let mut canvas = Canvas::new(&cave.inner);
+    for (p, dirs) in beams.iter().filter(|(_, d)| !d.is_empty()) {
+        canvas.paint([p], Rgb::YELLOW);
+        if let CaveElement::Empty = cave.inner[p] {
+            let glyph = match dirs[..] {
+                [d] => d.to_byte(),
+                _ => b'0' + dirs.len() as u8,
+            };
+            canvas.draw([p], glyph);
+        }
+    }
+    canvas
+}
+
+#[test]
+fn test_render() {
+    use aoc_core::Solver;
+
+    let cave = crate::Solution::parse_str(include_str!("../sample")).unwrap();
+    let canvas = render(&cave);
+    let text = canvas.text();
+    assert_eq!(text.lines().next(), Some(">|<<<\\...."));
+    let energized = (0..canvas.height())
+        .flat_map(|r| (0..canvas.width()).map(move |c| Position::new(r, c)))
+        .filter(|p| canvas.get(*p).is_some_and(|(_, c)| c.is_some()))
+        .count();
+    assert_eq!(energized, 46);
+}
@@ -0,0 +1,75 @@
+use aoc_core::rng::Rng;
+use grid::Grid;
+
+/// Generates a `size` by `size` map of heat losses from 1 to 9.
+///
+/// The size is at least 5, so the ultra crucible can reach the factory.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(5);
+    let map = Grid::filled(size, size, b'1').map(|_| b'1' + rng.index(9) as u8);
+    map.to_string()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// The least heat lost on the way to the bottom right, relaxing the heat lost arriving at
+    /// every tile from every direction after every number of straight steps until none changes.
+    fn least_heat_loss(map: &[Vec<usize>], min: usize, max: usize) -> usize {
+        let size = map.len();
+        let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+        let mut loss = vec![[[usize::MAX; 11]; 4]; size * size];
+        // The first step leaves the start to the right or down.
+        loss[1][0][1] = map[0][1];
+        loss[size][1][1] = map[1][0];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for tile in 0..size * size {
+                for (d, straight) in (0..4).flat_map(|d| (1..=max).map(move |s| (d, s))) {
+                    let lost = loss[tile][d][straight];
+                    if lost == usize::MAX {
+                        continue;
+                    }
+                    let turns = [(d, straight + 1), ((d + 1) % 4, 1), ((d + 3) % 4, 1)];
+                    for (next, s) in turns {
+                        if s > max || (next != d && straight < min) {
+                            continue;
+                        }
+                        let r = (tile / size) as isize + directions[next].0;
+                        let c = (tile % size) as isize + directions[next].1;
+                        if !(0..size as isize).contains(&r) || !(0..size as isize).contains(&c) {
+                            continue;
+                        }
+                        let (r, c) = (r as usize, c as usize);
+                        let entry = &mut loss[r * size + c][next][s];
+                        if lost + map[r][c] < *entry {
+                            *entry = lost + map[r][c];
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+        let target = &loss[size * size - 1];
+        (0..4)
+            .flat_map(|d| target[d][min..=max].iter())
+            .copied()
+            .min()
+            .unwrap()
+    }
+
+    for seed in 0..5 {
+        let text = generate(15, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let map: Vec<Vec<usize>> = text
+            .lines()
+            .map(|l| l.bytes().map(|b| (b - b'0') as usize).collect())
+            .collect();
+        assert_eq!(Solution::part1(&input), least_heat_loss(&map, 1, 3));
+        assert_eq!(Solution::part2(&input), least_heat_loss(&map, 4, 10));
+    }
+}
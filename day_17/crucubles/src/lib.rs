@@ -0,0 +1,134 @@
+mod generate;
+mod render;
+use aoc_core::{search, ParseError, Solver};
+pub use generate::generate;
+use grid::{Direction, Grid, Position};
+pub use render::render;
+use std::io::BufRead;
+
+/// Parses the heat loss map, whose bottom right block both kinds of crucibles have to reach.
+fn parse_map(input: impl BufRead) -> Result<Grid<usize>, ParseError> {
+    let map = Grid::parse(input, |b| match (b as char).to_digit(10) {
+        Some(d) => Ok(d as usize),
+        None => Err(ParseError::new(b as char, "a heat loss digit")),
+    })?;
+    let target = target(&map);
+    for (min, max, expected) in [
+        (1, 3, "a factory the crucible can reach"),
+        (4, 10, "a factory the ultra crucible can reach"),
+    ] {
+        let successors = |c: &Crucible| moves(&map, *c, min, max).map(|(next, _)| next);
+        if search::bfs(start(), successors, |c| reached(c, target, min)).is_none() {
+            let token = map[target].to_string();
+            return Err(ParseError::new(token, expected).at(target.row + 1, target.col + 1));
+        }
+    }
+    Ok(map)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The heat loss of every city block.
+    type Input = Grid<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    /// Finds the least heat loss for a crucible moving at most three blocks in a row.
+    fn part1(map: &Self::Input) -> usize {
+        run_maze(map, 1, 3).expect("A path to the factory")
+    }
+
+    /// Finds the least heat loss for an ultra crucible moving four to ten blocks in a row.
+    fn part2(map: &Self::Input) -> usize {
+        run_maze(map, 4, 10).expect("A path to the factory")
+    }
+}
+
+/// The block the crucible is on, the direction it moves in and how many blocks it moved that way.
+pub type Crucible = (Position, Direction, u8);
+
+/// Finds the least heat loss from the top left to the bottom right block for a crucible that has
+/// to move `min` to `max` blocks in a row.
+pub fn run_maze(map: &Grid<usize>, min: u8, max: u8) -> Option<usize> {
+    best_path(map, min, max).map(|(_, heat)| heat)
+}
+
+/// Finds the crucibles along a path with the least heat loss like [`run_maze`] and the heat lost.
+pub fn best_path(map: &Grid<usize>, min: u8, max: u8) -> Option<(Vec<Crucible>, usize)> {
+    let target = target(map);
+    let successors = |c: &Crucible| moves(map, *c, min, max);
+    // Every block costs at least one, so the manhattan distance never overestimates.
+    let heuristic = |(pos, _, _): &Crucible| pos.distance(target);
+    search::astar(start(), successors, heuristic, |c| reached(c, target, min))
+}
+
+/// The crucible at the start, the only one that has not moved at all and may head anywhere.
+fn start() -> Crucible {
+    (Position::new(0, 0), Direction::Right, 0)
+}
+
+/// The bottom right block with the factory.
+fn target(map: &Grid<usize>) -> Position {
+    Position::new(map.height() - 1, map.width() - 1)
+}
+
+/// Whether the crucible is at the `target` and able to stop there.
+fn reached(&(pos, _, x): &Crucible, target: Position, min: u8) -> bool {
+    pos == target && x >= min
+}
+
+/// The crucibles one block further on and the heat lost on entering the block.
+fn moves(
+    map: &Grid<usize>,
+    (pos, dir, x): Crucible,
+    min: u8,
+    max: u8,
+) -> impl Iterator<Item = (Crucible, usize)> + '_ {
+    let turns = (x >= min || x == 0).then(|| [(dir.turn_left(), 1), (dir.turn_right(), 1)]);
+    let straight = (x < max).then_some((dir, x + 1));
+    turns
+        .into_iter()
+        .flatten()
+        .chain(straight)
+        .filter_map(move |(d, n)| map.step(pos, d).map(|next| ((next, d, n), map[next])))
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 102);
+    assert_eq!(Solution::part2(&input), 94);
+}
+
+#[test]
+fn test_sample_ultra() {
+    let input = Solution::parse_str(include_str!("../sample_b")).unwrap();
+    assert_eq!(Solution::part2(&input), 71);
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Solution::parse_str("1").unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::new("1", "a factory the crucible can reach").at(1, 1)
+    );
+    let e = Solution::parse_str("11111").unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::new("1", "a factory the crucible can reach").at(1, 5)
+    );
+    let e = Solution::parse_str("12\n34").unwrap_err();
+    let expected = "a factory the ultra crucible can reach";
+    assert_eq!(e, ParseError::new("4", expected).at(2, 2));
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(17, env!("CARGO_MANIFEST_DIR"));
+}
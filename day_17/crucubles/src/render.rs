@@ -0,0 +1,34 @@
+use crate::best_path;
+use grid::{Canvas, Grid, Rgb, Tile};
+
+/// Draws the path of the crucible of part one losing the least heat.
+///
+/// Like in the puzzle, every block it enters shows the arrow of the direction it moves in.
+pub fn render(map: &Grid<usize>) -> Canvas {
+    let mut canvas = Canvas::new(&map.map(|d| b'0' + *d as u8));
+    if let Some((path, _)) = best_path(map, 1, 3) {
+        for (pos, dir, _) in &path[1..] {
+            canvas.draw([*pos], dir.to_byte());
+        }
+        canvas.paint(path.iter().map(|(pos, _, _)| *pos), Rgb::RED);
+    }
+    canvas
+}
+
+#[test]
+fn test_render() {
+    use aoc_core::Solver;
+
+    let map = crate::Solution::parse_str(include_str!("../sample")).unwrap();
+    let text = render(&map).text();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines[0], "2>>34^>>>1323");
+    let heat: usize = lines
+        .iter()
+        .flat_map(|l| l.bytes())
+        .zip(map.iter())
+        .filter(|(b, _)| !b.is_ascii_digit())
+        .map(|(_, (_, heat))| heat)
+        .sum();
+    assert_eq!(heat, 102);
+}
@@ -0,0 +1,156 @@
+use aoc_core::rng::Rng;
+use grid::{Direction, Grid, Position};
+
+/// The directions in the order of their digits in the hexadecimal instructions, each being a
+/// quarter turn clockwise from the one before.
+const CLOCKWISE: [Direction; 4] = [
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+    Direction::Up,
+];
+
+/// Generates a dig plan for a lagoon shaped like a random tree of `size` rooms joined by
+/// corridors.
+///
+/// The rooms and corridors are laid out on a lattice whose columns and rows get random widths, so
+/// the lagoon has neither holes nor trenches touching each other. The hexadecimal instructions
+/// dig the same tree with far wider columns and rows, turned by a random number of quarter turns.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let dug = random_tree(&mut rng, size.max(1));
+    let border = trace(&dug);
+    let cells = dug.width();
+    let small: Vec<u32> = (0..cells).map(|_| rng.range(1..9) as u32).collect();
+    // A trench spans at most all columns or rows, which must fit into five hexadecimal digits.
+    let max = 0xfffff / cells as u64;
+    let large: Vec<u32> = (0..cells).map(|_| rng.range(1..max + 1) as u32).collect();
+    let turn = rng.index(4);
+    let plan = dig_plan(&border, &small);
+    let hex = dig_plan(&border, &large);
+    let mut out = String::new();
+    for ((direction, amount), (hex_direction, hex_amount)) in plan.into_iter().zip(hex) {
+        let letter = match direction {
+            Direction::Up => 'U',
+            Direction::Right => 'R',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+        };
+        let digit = (CLOCKWISE.iter().position(|d| *d == hex_direction).unwrap() + turn) % 4;
+        out.push_str(&format!("{letter} {amount} (#{hex_amount:05x}{digit})\n"));
+    }
+    out
+}
+
+/// Digs a random tree of `len` rooms on a lattice of cells, the rooms being the cells with even
+/// coordinates and the corridors the cells between two of them.
+///
+/// The lattice is surrounded by a margin of cells that are never dug.
+fn random_tree(rng: &mut Rng, len: usize) -> Grid<bool> {
+    let side = (1..).find(|s| s * s >= 2 * len).unwrap();
+    let mut dug = Grid::filled(2 * side + 1, 2 * side + 1, false);
+    let mut rooms = vec![Position::new(rng.index(side), rng.index(side))];
+    let mut seen = Grid::filled(side, side, false);
+    seen[rooms[0]] = true;
+    while rooms.len() < len {
+        let room = *rng.choose(&rooms);
+        let direction = *rng.choose(&Direction::ALL);
+        let Some(next) = seen.step(room, direction).filter(|n| !seen[*n]) else {
+            continue;
+        };
+        seen[next] = true;
+        rooms.push(next);
+        dug[Position::new(room.row + next.row + 1, room.col + next.col + 1)] = true;
+    }
+    for room in rooms {
+        dug[Position::new(2 * room.row + 1, 2 * room.col + 1)] = true;
+    }
+    dug
+}
+
+/// A corner of a cell, given by the position of the cell it is the top left corner of.
+type Corner = Position;
+
+/// The sides of the dug cells along the border in clockwise order, starting with the top of the
+/// first dug cell. Each side is given by its direction and the column or row of its cell.
+fn trace(dug: &Grid<bool>) -> Vec<(Direction, usize)> {
+    // The side leaving every corner of a cell on the border, there being at most one as
+    // dug cells never touch only at a corner.
+    let mut sides: Grid<Option<(Direction, usize, Corner)>> =
+        Grid::filled(dug.width() + 1, dug.height() + 1, None);
+    for p in dug.positions().filter(|p| dug[*p]) {
+        let (r, c) = (p.row, p.col);
+        let at = Position::new;
+        if !dug[at(r - 1, c)] {
+            sides[at(r, c)] = Some((Direction::Right, c, at(r, c + 1)));
+        }
+        if !dug[at(r, c + 1)] {
+            sides[at(r, c + 1)] = Some((Direction::Down, r, at(r + 1, c + 1)));
+        }
+        if !dug[at(r + 1, c)] {
+            sides[at(r + 1, c + 1)] = Some((Direction::Left, c, at(r + 1, c)));
+        }
+        if !dug[at(r, c - 1)] {
+            sides[at(r + 1, c)] = Some((Direction::Up, r, at(r, c)));
+        }
+    }
+    let start = dug.positions().find(|p| dug[*p]).unwrap();
+    let mut border = Vec::new();
+    let mut corner = start;
+    loop {
+        let (direction, cell, next) = sides[corner].expect("A closed border");
+        border.push((direction, cell));
+        corner = next;
+        if corner == start {
+            return border;
+        }
+    }
+}
+
+/// Turns the border into dig instructions, the columns and rows having the given widths.
+fn dig_plan(border: &[(Direction, usize)], widths: &[u32]) -> Vec<(Direction, u32)> {
+    let mut plan: Vec<(Direction, u32)> = Vec::new();
+    for &(direction, cell) in border {
+        match plan.last_mut() {
+            Some((last, amount)) if *last == direction => *amount += widths[cell],
+            _ => plan.push((direction, widths[cell])),
+        }
+    }
+    plan
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    // The trench and the area enclosed by the middle of it.
+    fn volume(plan: impl Iterator<Item = (Direction, u32)>) -> usize {
+        let (mut x, mut y, mut area, mut trench) = (0i64, 0i64, 0i64, 0i64);
+        for (direction, amount) in plan {
+            let amount = amount as i64;
+            let (nx, ny) = match direction {
+                Direction::Up => (x, y - amount),
+                Direction::Right => (x + amount, y),
+                Direction::Down => (x, y + amount),
+                Direction::Left => (x - amount, y),
+            };
+            area += x * ny - nx * y;
+            trench += amount;
+            (x, y) = (nx, ny);
+        }
+        (area.abs() / 2 + trench / 2 + 1) as usize
+    }
+
+    for seed in 0..5 {
+        let input = Solution::parse_str(&generate(30, seed)).unwrap();
+        let plan = input.iter().map(|i| (i.instr, i.amount));
+        assert_eq!(Solution::part1(&input), volume(plan));
+        let hex = input.iter().map(|i| {
+            let amount = u32::from_str_radix(&i.color[..5], 16).unwrap();
+            let digit = i.color.as_bytes()[5] - b'0';
+            (CLOCKWISE[digit as usize], amount)
+        });
+        assert_eq!(Solution::part2(&input), volume(hex));
+    }
+}
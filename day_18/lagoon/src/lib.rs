@@ -0,0 +1,356 @@
+mod generate;
+mod render;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use grid::Direction;
+pub use render::render;
+use std::{collections::VecDeque, io::BufRead, str::FromStr};
+
+#[derive(Debug, Clone)]
+pub struct Instruction {
+    pub instr: Direction,
+    pub amount: u32,
+    pub color: String,
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut split = s.split_whitespace();
+        let dir = split.next().ok_or(ParseError::end("a direction"))?;
+        let dir = match dir.as_bytes() {
+            [b] => parse_direction(*b).map_err(|e| e.within(s, dir))?,
+            _ => return Err(ParseError::in_text(s, dir, "`U`, `R`, `D` or `L`")),
+        };
+        let amount = split.next().ok_or(ParseError::end("a distance"))?;
+        let amount: u32 = parse_token(s, amount, "a distance")?;
+        let color = split.next().ok_or(ParseError::end("a color"))?;
+        let Some(hex) = color
+            .strip_prefix("(#")
+            .and_then(|c| c.strip_suffix(')'))
+            .filter(|c| {
+                c.len() == 6
+                    && c.bytes().all(|b| b.is_ascii_hexdigit())
+                    && (b'0'..=b'3').contains(&c.as_bytes()[5])
+            })
+        else {
+            return Err(ParseError::in_text(
+                s,
+                color,
+                "an encoded instruction `(#<hex>)`",
+            ));
+        };
+        Ok(Self {
+            instr: dir,
+            amount,
+            color: hex.to_owned(),
+        })
+    }
+}
+
+impl Instruction {
+    /// The instruction hidden in the hexadecimal color code, its distance given by the first
+    /// five digits and its direction by the last.
+    pub fn decode(&self) -> Instruction {
+        let (n, d) = self.color.split_at(5);
+        let amount = u32::from_str_radix(n, 16).unwrap();
+        let d = match d {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            _ => panic!("unknown encoded direction"),
+        };
+        Instruction {
+            instr: d,
+            amount,
+            color: self.color.clone(),
+        }
+    }
+}
+
+fn parse_direction(value: u8) -> Result<Direction, ParseError> {
+    match value {
+        b'U' => Ok(Direction::Up),
+        b'R' => Ok(Direction::Right),
+        b'D' => Ok(Direction::Down),
+        b'L' => Ok(Direction::Left),
+        _ => Err(ParseError::new(value as char, "`U`, `R`, `D` or `L`")),
+    }
+}
+
+fn parse_instructions(input: impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    let instructions: Vec<Instruction> = aoc_core::lines(input)
+        .map(|l| {
+            let (n, l) = l?;
+            l.parse().map_err(|e: ParseError| e.on_line(n))
+        })
+        .collect::<Result<_, _>>()?;
+    // The trench starts and ends with the first instruction.
+    if instructions.is_empty() {
+        return Err(ParseError::end("a dig instruction"));
+    }
+    Ok(instructions)
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Instruction>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_instructions(input)
+    }
+
+    /// Calculates the cubic meters of lava the lagoon can hold.
+    fn part1(instructions: &Self::Input) -> usize {
+        lagoon_size(instructions)
+    }
+
+    /// Calculates the lagoon size with the instructions decoded from the hexadecimal color codes.
+    fn part2(instructions: &Self::Input) -> usize {
+        let instructions: Vec<Instruction> = instructions.iter().map(Instruction::decode).collect();
+        lagoon_size(&instructions)
+    }
+}
+
+/// Counts the cubic meters of the trench dug by the instructions and the interior it encloses.
+pub fn lagoon_size(instructions: &[Instruction]) -> usize {
+    let borders = dig(instructions);
+    calc_ground_from_border(&borders)
+}
+
+fn calc_ground_from_border(border: &VecDeque<Vec<(Tile, usize)>>) -> usize {
+    let mut ground_tiles = 0;
+    for row in border {
+        for (i, border) in row.iter().enumerate() {
+            if i == 0 {
+                ground_tiles += 1;
+                continue;
+            }
+            match border.0 {
+                Tile::Vertical => {
+                    let mut inter = 0;
+                    let mut dir: Option<Tile> = None;
+                    for p in row[..i].iter() {
+                        match p.0 {
+                            Tile::Vertical => {
+                                inter += 1;
+                            }
+                            Tile::CornerDown => match dir {
+                                Some(t) => {
+                                    if t == Tile::CornerUp {
+                                        inter += 1;
+                                    }
+                                    dir = None;
+                                }
+                                None => {
+                                    dir = Some(p.0);
+                                }
+                            },
+                            Tile::CornerUp => match dir {
+                                Some(t) => {
+                                    if t == Tile::CornerDown {
+                                        inter += 1;
+                                    }
+                                    dir = None;
+                                }
+                                None => {
+                                    dir = Some(p.0);
+                                }
+                            },
+                        }
+                    }
+                    if inter % 2 == 0 {
+                        ground_tiles += 1;
+                    } else {
+                        ground_tiles += (row[i - 1].1..row[i].1).count();
+                    }
+                }
+                Tile::CornerDown | Tile::CornerUp => {
+                    let mut corners = 0;
+                    let mut inter = 0;
+                    let mut dir: Option<Tile> = None;
+                    for p in row[..i].iter() {
+                        match p.0 {
+                            Tile::Vertical => {
+                                inter += 1;
+                            }
+                            Tile::CornerDown => {
+                                corners += 1;
+                                match dir {
+                                    Some(t) => {
+                                        if t == Tile::CornerUp {
+                                            inter += 1;
+                                        }
+                                        dir = None;
+                                    }
+                                    None => {
+                                        dir = Some(p.0);
+                                    }
+                                }
+                            }
+                            Tile::CornerUp => {
+                                corners += 1;
+                                match dir {
+                                    Some(t) => {
+                                        if t == Tile::CornerDown {
+                                            inter += 1;
+                                        }
+                                        dir = None;
+                                    }
+                                    None => {
+                                        dir = Some(p.0);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    if corners % 2 != 0 {
+                        ground_tiles += (row[i - 1].1..row[i].1).count();
+                    } else if inter % 2 == 0 {
+                        ground_tiles += 1;
+                    } else {
+                        ground_tiles += (row[i - 1].1..row[i].1).count();
+                    }
+                }
+            }
+        }
+    }
+    ground_tiles
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Tile {
+    Vertical,
+    CornerDown,
+    CornerUp,
+}
+
+fn dig(instructions: &[Instruction]) -> VecDeque<Vec<(Tile, usize)>> {
+    let mut x: i64 = 0;
+    let mut y: usize = 0;
+    let mut borders: VecDeque<Vec<(Tile, i64)>> = VecDeque::new();
+    borders.push_back(Vec::new());
+    let start = &instructions[0].instr;
+    let mut prev_dir = &instructions[0].instr;
+    for i in instructions {
+        match i.instr {
+            Direction::Up => {
+                if !prev_dir.is_vertical() {
+                    borders[y].push((Tile::CornerUp, x));
+                }
+                for _ in 0..i.amount {
+                    let (new_y, is_overflow) = y.overflowing_sub(1);
+                    if is_overflow {
+                        borders.push_front(vec![(Tile::Vertical, x)]);
+                    } else {
+                        y = new_y;
+                        borders[y].push((Tile::Vertical, x));
+                    }
+                }
+            }
+            Direction::Right => {
+                match prev_dir {
+                    Direction::Up => {
+                        borders[y].last_mut().unwrap().0 = Tile::CornerDown;
+                    }
+                    Direction::Down => {
+                        borders[y].last_mut().unwrap().0 = Tile::CornerUp;
+                    }
+                    _ => {}
+                }
+                x += i.amount as i64;
+            }
+            Direction::Down => {
+                if !prev_dir.is_vertical() {
+                    borders[y].push((Tile::CornerDown, x));
+                }
+                for _ in 0..i.amount {
+                    y += 1;
+                    if y >= borders.len() {
+                        borders.push_back(vec![(Tile::Vertical, x)]);
+                    } else {
+                        borders[y].push((Tile::Vertical, x));
+                    }
+                }
+            }
+            Direction::Left => {
+                match prev_dir {
+                    Direction::Up => {
+                        borders[y].last_mut().unwrap().0 = Tile::CornerDown;
+                    }
+                    Direction::Down => {
+                        borders[y].last_mut().unwrap().0 = Tile::CornerUp;
+                    }
+                    _ => {}
+                }
+                x -= i.amount as i64;
+            }
+        }
+        prev_dir = &i.instr;
+    }
+    // Close the loop at the start which is only part of the border if the last dig was vertical
+    let last = instructions.last().unwrap().instr;
+    let corner = match (last, start) {
+        (Direction::Up, Direction::Right)
+        | (Direction::Up, Direction::Left)
+        | (Direction::Right, Direction::Down)
+        | (Direction::Left, Direction::Down) => Some(Tile::CornerDown),
+        (Direction::Right, Direction::Up)
+        | (Direction::Down, Direction::Right)
+        | (Direction::Down, Direction::Left)
+        | (Direction::Left, Direction::Up) => Some(Tile::CornerUp),
+        _ => None,
+    };
+    match corner {
+        Some(c) if last.is_vertical() => borders[y].last_mut().unwrap().0 = c,
+        Some(c) => borders[y].push((c, x)),
+        None => {}
+    }
+    let mut shift = 0;
+    borders.iter_mut().for_each(|v| {
+        v.sort_by_key(|a| a.1);
+        shift = shift.min(v[0].1);
+    });
+    shift = shift.abs();
+    borders
+        .into_iter()
+        .map(|v| {
+            v.into_iter()
+                .map(|(t, e)| (t, (e + shift) as usize))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_instruction() {
+    let i: Instruction = "R 6 (#70c710)".parse().unwrap();
+    assert!(matches!(i.instr, Direction::Right));
+    assert_eq!((i.amount, i.color.as_str()), (6, "70c710"));
+    let decoded = i.decode();
+    assert!(matches!(decoded.instr, Direction::Right));
+    assert_eq!(decoded.amount, 461937);
+    let e = "X 6 (#70c710)".parse::<Instruction>().unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (1, "X"));
+    let e = "R 6 (#70c717)".parse::<Instruction>().unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (5, "(#70c717)"));
+    let e = Solution::parse_str("").unwrap_err();
+    assert_eq!(e.expected, "a dig instruction");
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 62);
+    assert_eq!(Solution::part2(&input), 952408144115);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(18, env!("CARGO_MANIFEST_DIR"));
+}
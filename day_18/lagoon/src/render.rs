@@ -0,0 +1,97 @@
+use crate::Instruction;
+use aoc_core::search;
+use grid::{Canvas, Grid, Position, Rgb};
+
+/// Draws the trench dug by the instructions of part one, each meter in the color of its
+/// instruction, and the interior dug out of it.
+pub fn render(instructions: &[Instruction]) -> Canvas {
+    let mut trench = vec![((0, 0), Rgb::GRAY)];
+    let (mut row, mut col) = (0_isize, 0_isize);
+    for i in instructions {
+        let (dr, dc) = i.instr.offset();
+        let color = u32::from_str_radix(&i.color, 16).unwrap_or_default();
+        let color = Rgb((color >> 16) as u8, (color >> 8) as u8, color as u8);
+        for _ in 0..i.amount {
+            (row, col) = (row + dr, col + dc);
+            trench.push(((row, col), color));
+        }
+    }
+    let top = trench
+        .iter()
+        .map(|((r, _), _)| *r)
+        .min()
+        .unwrap_or_default();
+    let left = trench
+        .iter()
+        .map(|((_, c), _)| *c)
+        .min()
+        .unwrap_or_default();
+    let height = trench
+        .iter()
+        .map(|((r, _), _)| r - top + 1)
+        .max()
+        .unwrap_or(1);
+    let width = trench
+        .iter()
+        .map(|((_, c), _)| c - left + 1)
+        .max()
+        .unwrap_or(1);
+    let trench: Vec<(Position, Rgb)> = trench
+        .into_iter()
+        .map(|((r, c), color)| {
+            let p = Position::new((r - top) as usize, (c - left) as usize);
+            (p, color)
+        })
+        .collect();
+    let mut dug = Grid::filled(width as usize, height as usize, b'.');
+    for (p, _) in &trench {
+        dug[*p] = b'#';
+    }
+
+    // Whatever the ground at the edges leads to lies outside of the trench. The search starts
+    // beyond the map, next to every tile on its edges, which have fewer than four neighbours.
+    let ground = |p: &Position| dug[*p] == b'.';
+    let outside = search::bfs_distances(None, |p: &Option<Position>| -> Vec<Option<Position>> {
+        let next: Vec<Position> = match p {
+            None => dug
+                .positions()
+                .filter(|p| dug.neighbours(*p).count() < 4)
+                .collect(),
+            Some(p) => dug.neighbours(*p).collect(),
+        };
+        next.into_iter().filter(ground).map(Some).collect()
+    });
+    let interior: Vec<Position> = dug
+        .positions()
+        .filter(|p| ground(p) && outside.get(&Some(*p)).is_none())
+        .collect();
+
+    let mut canvas = Canvas::new(&dug);
+    canvas
+        .paint(interior.iter().copied(), Rgb::BLUE)
+        .draw(interior, b'#');
+    for (p, color) in trench {
+        canvas.paint([p], color);
+    }
+    canvas
+}
+
+#[test]
+fn test_render() {
+    use aoc_core::Solver;
+
+    let instructions = crate::Solution::parse_str(include_str!("../sample")).unwrap();
+    let canvas = render(&instructions);
+    assert_eq!(
+        canvas.text(),
+        "#######\n#######\n#######\n..#####\n..#####\n#######\n#####..\n#######\n.######\n.######\n"
+    );
+    assert_eq!(
+        canvas.get(Position::new(0, 1)),
+        Some((b'#', Some(Rgb(0x70, 0xc7, 0x10))))
+    );
+    assert_eq!(
+        canvas.get(Position::new(1, 1)).and_then(|(_, c)| c),
+        Some(Rgb::BLUE)
+    );
+}
@@ -0,0 +1,181 @@
+use aoc_core::rng::Rng;
+
+/// Generates `size` workflows and as many parts to sort.
+///
+/// The workflows form a tree rooted in `in`, every workflow being sent to by exactly one rule of
+/// an earlier one, so all of them are used and no part is sent around in circles.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let len = size.max(1);
+    let names = names(&mut rng, len);
+    let mut targets: Vec<Vec<&str>> = vec![Vec::new(); len];
+    for (i, name) in names.iter().enumerate().skip(1) {
+        targets[rng.index(i)].push(name);
+    }
+    let mut workflows = Vec::new();
+    for (name, mut targets) in names.iter().zip(targets) {
+        // Pad the rules with ones accepting or rejecting, the last target being the fallback.
+        let len = rng.range(2..6) as usize;
+        while targets.len() < len {
+            targets.push(*rng.choose(&["A", "R"]));
+        }
+        rng.shuffle(&mut targets);
+        let fallback = targets.pop().unwrap();
+        let mut rules: Vec<String> = targets
+            .into_iter()
+            .map(|target| {
+                let category = *rng.choose(&['x', 'm', 'a', 's']);
+                let operation = *rng.choose(&['<', '>']);
+                let value = rng.range(1..4001);
+                format!("{category}{operation}{value}:{target}")
+            })
+            .collect();
+        rules.push(fallback.to_owned());
+        workflows.push(format!("{name}{{{}}}", rules.join(",")));
+    }
+    rng.shuffle(&mut workflows);
+
+    let mut out = workflows.join("\n");
+    out.push_str("\n\n");
+    for _ in 0..len {
+        let [x, m, a, s] = [(); 4].map(|_| rng.range(1..4001));
+        out.push_str(&format!("{{x={x},m={m},a={a},s={s}}}\n"));
+    }
+    out
+}
+
+/// `len` distinct names, the first one being `in` and the others of at least three lowercase
+/// letters, longer ones once three run out.
+fn names(rng: &mut Rng, len: usize) -> Vec<String> {
+    let mut letters = 3;
+    while 26usize.pow(letters) < len {
+        letters += 1;
+    }
+    let count = 26u64.pow(letters);
+    // A stride coprime to the number of names steps through all of them before coming back.
+    let stride = loop {
+        let stride = rng.range(1..count);
+        if !stride.is_multiple_of(2) && !stride.is_multiple_of(13) {
+            break stride;
+        }
+    };
+    let start = rng.range(0..count);
+    let mut names: Vec<String> = (1..len as u64)
+        .map(|i| name((start + i * stride) % count, letters))
+        .collect();
+    rng.shuffle(&mut names);
+    names.insert(0, "in".to_owned());
+    names
+}
+
+/// The name of `letters` lowercase letters spelling `index` in base 26.
+fn name(mut index: u64, letters: u32) -> String {
+    (0..letters)
+        .map(|_| {
+            let letter = (b'a' + (index % 26) as u8) as char;
+            index /= 26;
+            letter
+        })
+        .collect()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+    use std::collections::HashMap;
+
+    /// The workflows by name, each rule with its condition on a category and its target.
+    type Workflows<'a> = HashMap<&'a str, Vec<(Option<(usize, char, u32)>, &'a str)>>;
+
+    fn parse(text: &str) -> (Workflows<'_>, Vec<[u32; 4]>) {
+        let (workflows, parts) = text.split_once("\n\n").unwrap();
+        let workflows = workflows
+            .lines()
+            .map(|line| {
+                let (name, rules) = line.strip_suffix('}').unwrap().split_once('{').unwrap();
+                let rules = rules.split(',').map(|rule| match rule.split_once(':') {
+                    Some((condition, target)) => {
+                        let category = "xmas".find(&condition[..1]).unwrap();
+                        let value = condition[2..].parse().unwrap();
+                        (
+                            Some((category, condition.as_bytes()[1] as char, value)),
+                            target,
+                        )
+                    }
+                    None => (None, rule),
+                });
+                (name, rules.collect())
+            })
+            .collect();
+        let parts = parts
+            .lines()
+            .map(|line| {
+                let ratings = line.trim_matches(['{', '}']).split(',');
+                let ratings: Vec<u32> = ratings.map(|r| r[2..].parse().unwrap()).collect();
+                ratings.try_into().unwrap()
+            })
+            .collect();
+        (workflows, parts)
+    }
+
+    /// Whether the workflows starting with `in` accept the part.
+    fn accepts(workflows: &Workflows, part: &[u32; 4]) -> bool {
+        let mut name = "in";
+        while let Some(rules) = workflows.get(name) {
+            name = rules
+                .iter()
+                .find(|(condition, _)| match condition {
+                    Some((c, '<', v)) => part[*c] < *v,
+                    Some((c, _, v)) => part[*c] > *v,
+                    None => true,
+                })
+                .unwrap()
+                .1;
+        }
+        name == "A"
+    }
+
+    let input = Solution::parse_str(&generate(20000, 1)).unwrap();
+    assert_eq!(input.0.names.len(), 20000);
+    for seed in 0..5 {
+        let text = generate(100, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!((input.1.len(), input.0.names.len()), (100, 100));
+        let (workflows, parts) = parse(&text);
+        let accepted = parts.iter().filter(|p| accepts(&workflows, p));
+        assert_eq!(
+            Solution::part1(&input),
+            accepted.map(|p| p.iter().sum::<u32>()).sum::<u32>()
+        );
+        // The rules split the ratings of every category into ranges whose parts are all treated
+        // alike, so trying the first part of each combination of ranges is enough.
+        let text = generate(12, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let (workflows, _) = parse(&text);
+        let mut bounds = [(); 4].map(|_| vec![1, 4001]);
+        for (c, operation, v) in workflows.values().flatten().filter_map(|r| r.0) {
+            bounds[c].push(if operation == '<' { v } else { v + 1 });
+        }
+        let ranges = bounds.map(|mut b| {
+            b.sort_unstable();
+            b.dedup();
+            b.windows(2)
+                .map(|w| (w[0], (w[1] - w[0]) as usize))
+                .collect::<Vec<_>>()
+        });
+        let mut combinations = 0;
+        for x in &ranges[0] {
+            for m in &ranges[1] {
+                for a in &ranges[2] {
+                    for s in &ranges[3] {
+                        if accepts(&workflows, &[x.0, m.0, a.0, s.0]) {
+                            combinations += x.1 * m.1 * a.1 * s.1;
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(Solution::part2(&input), combinations);
+    }
+}
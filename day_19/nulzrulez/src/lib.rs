@@ -1,29 +1,43 @@
-use std::collections::{HashSet, VecDeque};
+mod generate;
+use aoc_core::{
+    interner::Interner,
+    interval::{Interval, IntervalBox},
+    parse_token, ParseError, Solver,
+};
+pub use generate::generate;
+use std::{
+    collections::{HashSet, VecDeque},
+    io::BufRead,
+    str::FromStr,
+};
 
 #[derive(Debug, Clone)]
 pub struct RulezNulz {
     init: usize,
-    /// A perfect hash table for 3 Byte strings
+    /// The names of the workflows, their ids index `buffer`.
+    names: Interner,
     buffer: Vec<Option<RuleContainer>>,
 }
 
 impl RulezNulz {
-    pub fn new(init: usize) -> Self {
+    /// The workflows starting with the one named `init`.
+    pub fn new(init: &str) -> Self {
+        let mut names = Interner::new();
         RulezNulz {
-            init,
-            buffer: vec![None; 18278],
+            init: names.intern(init) as usize,
+            names,
+            buffer: Vec::new(),
         }
     }
 
-    pub fn insert(&mut self, hash: usize, val: RuleContainer) {
-        if self.buffer[hash].is_some() {
-            println!("Overriding rule at {hash}");
-        }
-        self.buffer[hash] = Some(val);
+    /// Stores the workflow `id`, returning the one it replaces if it was defined before.
+    pub fn insert(&mut self, id: usize, val: RuleContainer) -> Option<RuleContainer> {
+        self.buffer.resize(self.names.len(), None);
+        self.buffer[id].replace(val)
     }
 
-    pub fn get(&self, hash: usize) -> &RuleContainer {
-        self.buffer[hash].as_ref().unwrap()
+    pub fn get(&self, id: usize) -> &RuleContainer {
+        self.buffer[id].as_ref().unwrap()
     }
 
     pub fn is_accepted(&self, p: Part) -> bool {
@@ -47,27 +61,28 @@ impl RulezNulz {
 
     pub fn range_discovery(&self) -> Vec<RangePart> {
         let mut r = Vec::new();
-        let start = RangePart::new();
+        let start = all_ratings();
         let mut queue = VecDeque::new();
         queue.push_back((start, RuleResult::Next(self.init)));
-        while let Some((mut range, command)) = queue.pop_front() {
+        while let Some((range, command)) = queue.pop_front() {
             match command {
                 RuleResult::Next(n) => {
                     let rule_container = self.buffer[n].as_ref().unwrap();
+                    // The ratings no rule has matched so far.
+                    let mut rest = Some(range);
                     for rule in &rule_container.rules {
-                        match rule.match_range_part(range) {
-                            RangeRuleResult::Finish(r) => {
-                                queue.push_back(r);
-                                continue;
-                            }
-                            RangeRuleResult::Split((f, c)) => {
-                                queue.push_back(f);
-                                range = c;
-                            }
-                            RangeRuleResult::NoMatch(_) => {}
+                        let Some(range) = rest else {
+                            break;
+                        };
+                        let (matched, unmatched) = rule.split(range);
+                        if let Some(matched) = matched {
+                            queue.push_back((matched, rule.result));
                         }
+                        rest = unmatched;
+                    }
+                    if let Some(range) = rest {
+                        queue.push_back((range, rule_container.finaly));
                     }
-                    queue.push_back((range, rule_container.finaly))
                 }
                 RuleResult::Accept => r.push(range),
                 RuleResult::Reject => {}
@@ -75,21 +90,6 @@ impl RulezNulz {
         }
         r
     }
-
-    pub fn hash(key: &str) -> usize {
-        assert!(key.len() <= 3);
-        key.as_bytes()
-            .iter()
-            .map(|b| {
-                if !(97..=122).contains(b) {
-                    panic!("PerfectHash only supports lower character keys");
-                }
-                b - 96
-            })
-            .rev()
-            .enumerate()
-            .fold(0, |acc, (i, e)| acc + e as usize * 26_usize.pow(i as u32))
-    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -109,66 +109,39 @@ impl RuleContainer {
     }
 }
 
-impl From<&str> for RuleContainer {
-    fn from(value: &str) -> Self {
+impl RuleContainer {
+    /// Parses the rules of a workflow like `a<2006:qkq,m>2090:A,rfg`.
+    fn parse(s: &str, names: &mut Interner) -> Result<Self, ParseError> {
         let mut v: Vec<Rule> = Vec::new();
-        for rule in value.split(',') {
+        for rule in s.split(',') {
             if rule.chars().any(|c| c == '<' || c == '>') {
-                v.push(rule.into());
+                v.push(Rule::parse(rule, names).map_err(|e| e.within(s, rule))?);
             } else {
-                let finaly = match rule {
-                    "A" => RuleResult::Accept,
-                    "R" => RuleResult::Reject,
-                    _ => RuleResult::Next(RulezNulz::hash(rule)),
-                };
-                return Self { rules: v, finaly };
+                let finaly = parse_target(names, s, rule)?;
+                return Ok(Self { rules: v, finaly });
             }
         }
-        panic!("Needs a final instruction")
+        Err(ParseError::end("a final instruction"))
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct RangePart {
-    x: (u32, u32),
-    m: (u32, u32),
-    a: (u32, u32),
-    s: (u32, u32),
-}
+/// The ranges of the `x`, `m`, `s` and `a` ratings in the order of [`PartValue`].
+pub type RangePart = IntervalBox<u32, 4>;
 
-impl RangePart {
-    pub fn new() -> Self {
-        Self {
-            x: (1, 4000),
-            m: (1, 4000),
-            a: (1, 4000),
-            s: (1, 4000),
-        }
-    }
+/// The highest rating of a category.
+pub const MAX_RATING: u32 = 4000;
 
-    pub fn prod_val(&self) -> usize {
-        (self.s.0..=self.s.1).count()
-            * (self.a.0..=self.a.1).count()
-            * (self.m.0..=self.m.1).count()
-            * (self.x.0..=self.x.1).count()
-    }
+/// All possible ratings from 1 to 4000.
+pub fn all_ratings() -> RangePart {
+    IntervalBox::new([Interval::new(1, MAX_RATING + 1); 4])
+}
 
-    fn set_part_value(&mut self, k: PartValue, v: (u32, u32)) {
-        match k {
-            PartValue::X => self.x = v,
-            PartValue::M => self.m = v,
-            PartValue::S => self.s = v,
-            PartValue::A => self.a = v,
-        }
-    }
-
-    fn get_part_value(&self, v: PartValue) -> (u32, u32) {
-        match v {
-            PartValue::X => self.x,
-            PartValue::M => self.m,
-            PartValue::S => self.s,
-            PartValue::A => self.a,
-        }
+/// Parses a rating of a part or the rating a rule compares with, which is at most
+/// [`MAX_RATING`].
+fn parse_rating(text: &str, token: &str) -> Result<u32, ParseError> {
+    match parse_token(text, token, "a rating")? {
+        rating if rating <= MAX_RATING => Ok(rating),
+        _ => Err(ParseError::in_text(text, token, "a rating up to 4000")),
     }
 }
 
@@ -180,28 +153,39 @@ pub struct Part {
     s: u32,
 }
 
-impl From<&str> for Part {
-    fn from(value: &str) -> Self {
+impl FromStr for Part {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
         let mut v: [Option<u32>; 4] = [None; 4];
-        let striped = value
-            .strip_prefix("{")
-            .map(|s| s.strip_suffix("}").unwrap())
-            .unwrap();
+        let Some(striped) = value.strip_prefix('{').and_then(|v| v.strip_suffix('}')) else {
+            return Err(ParseError::in_text(
+                value,
+                value,
+                "ratings enclosed in `{}`",
+            ));
+        };
         for val in striped.split(',') {
-            match &val.chars().nth(0).unwrap() {
-                'x' => v[0] = Some(val[2..].parse().unwrap()),
-                'm' => v[1] = Some(val[2..].parse().unwrap()),
-                'a' => v[2] = Some(val[2..].parse().unwrap()),
-                's' => v[3] = Some(val[2..].parse().unwrap()),
-                _ => panic!("Unknown part value"),
-            }
-        }
-        Self {
-            x: v[0].unwrap(),
-            m: v[1].unwrap(),
-            a: v[2].unwrap(),
-            s: v[3].unwrap(),
+            let Some((category, rating)) = val.split_once('=') else {
+                return Err(ParseError::in_text(value, val, "`<category>=<rating>`"));
+            };
+            let i = match category {
+                "x" => 0,
+                "m" => 1,
+                "a" => 2,
+                "s" => 3,
+                _ => return Err(ParseError::in_text(value, category, "`x`, `m`, `a` or `s`")),
+            };
+            v[i] = Some(parse_rating(value, rating)?);
         }
+        let [Some(x), Some(m), Some(a), Some(s)] = v else {
+            return Err(ParseError::in_text(
+                value,
+                value,
+                "ratings for `x`, `m`, `a` and `s`",
+            ));
+        };
+        Ok(Self { x, m, a, s })
     }
 }
 
@@ -236,47 +220,16 @@ pub struct Rule {
     result: RuleResult,
 }
 
-#[derive(Debug, Clone, Copy)]
-enum RangeRuleResult {
-    Finish((RangePart, RuleResult)),
-    Split(((RangePart, RuleResult), RangePart)),
-    NoMatch(RangePart),
-}
-
 impl Rule {
-    // Possible matches are:
-    // - None
-    // - Splitting
-    // - Full
-    fn match_range_part(&self, p: RangePart) -> RangeRuleResult {
-        let (s, e) = p.get_part_value(self.val);
+    /// Splits the ratings into the ones matching the rule and the rest, `None` for an empty part.
+    fn split(&self, p: RangePart) -> (Option<RangePart>, Option<RangePart>) {
+        let axis = self.val as usize;
         match self.op {
             Operation::Greater => {
-                if (s..e).contains(&self.cmp) {
-                    let mut finished = p;
-                    let mut continued = p;
-                    finished.set_part_value(self.val, (self.cmp + 1, e));
-                    continued.set_part_value(self.val, (s, self.cmp));
-                    RangeRuleResult::Split(((finished, self.result), continued))
-                } else if self.cmp < s {
-                    RangeRuleResult::Finish((p, self.result))
-                } else {
-                    RangeRuleResult::NoMatch(p)
-                }
-            }
-            Operation::Smaller => {
-                if (s + 1..=e).contains(&self.cmp) {
-                    let mut finished = p;
-                    let mut continued = p;
-                    finished.set_part_value(self.val, (s, self.cmp - 1));
-                    continued.set_part_value(self.val, (self.cmp, e));
-                    RangeRuleResult::Split(((finished, self.result), continued))
-                } else if self.cmp > e {
-                    RangeRuleResult::Finish((p, self.result))
-                } else {
-                    RangeRuleResult::NoMatch(p)
-                }
+                let (rest, matched) = p.split_at(axis, self.cmp + 1);
+                (matched, rest)
             }
+            Operation::Smaller => p.split_at(axis, self.cmp),
         }
     }
 
@@ -298,54 +251,58 @@ impl Rule {
     }
 }
 
-impl From<&str> for Rule {
-    fn from(value: &str) -> Self {
-        let (o, o_i, c): (Option<Operation>, Option<usize>, Option<usize>) = value
-            .as_bytes()
-            .iter()
-            .enumerate()
-            .fold((None, None, None), |mut acc, (i, b)| {
-                match b {
-                    b'<' => {
-                        assert!(acc.0.is_none());
-                        acc.0 = Some(Operation::Smaller);
-                        acc.1 = Some(i);
-                    }
-                    b'>' => {
-                        assert!(acc.1.is_none());
-                        acc.0 = Some(Operation::Greater);
-                        acc.1 = Some(i);
-                    }
-                    b':' => {
-                        assert!(acc.2.is_none());
-                        acc.2 = Some(i);
-                    }
-                    _ => {}
-                }
-                acc
-            });
-        let op = o.unwrap();
-        let o_i = o_i.unwrap();
-        let val = match &value[..o_i] {
+impl Rule {
+    /// Parses a single rule like `a<2006:qkq`.
+    fn parse(s: &str, names: &mut Interner) -> Result<Self, ParseError> {
+        let Some(o_i) = s.find(['<', '>']) else {
+            return Err(ParseError::in_text(s, s, "a condition like `a<2006:qkq`"));
+        };
+        let op = match s.as_bytes()[o_i] {
+            b'<' => Operation::Smaller,
+            _ => Operation::Greater,
+        };
+        let val = match &s[..o_i] {
             "a" => PartValue::A,
             "x" => PartValue::X,
             "m" => PartValue::M,
             "s" => PartValue::S,
-            _ => panic!("Unknown part"),
+            category => {
+                return Err(ParseError::in_text(s, category, "`x`, `m`, `a` or `s`"));
+            }
         };
-        let c = c.unwrap();
-        let cmp = value[o_i + 1..c].parse::<u32>().unwrap();
-        let result = match &value[c + 1..] {
-            "A" => RuleResult::Accept,
-            "R" => RuleResult::Reject,
-            _ => RuleResult::Next(RulezNulz::hash(&value[c + 1..])),
+        let Some(c) = s[o_i..].find(':').map(|c| c + o_i) else {
+            return Err(ParseError::in_text(
+                s,
+                s,
+                "a condition followed by `:<target>`",
+            ));
         };
-        Self {
+        let cmp = parse_rating(s, &s[o_i + 1..c])?;
+        let result = parse_target(names, s, &s[c + 1..])?;
+        Ok(Self {
             val,
             op,
             cmp,
             result,
-        }
+        })
+    }
+}
+
+/// Parses the target of a rule which is a workflow or the acceptance or rejection of a part.
+fn parse_target(names: &mut Interner, text: &str, target: &str) -> Result<RuleResult, ParseError> {
+    Ok(match target {
+        "A" => RuleResult::Accept,
+        "R" => RuleResult::Reject,
+        _ => RuleResult::Next(parse_workflow_name(names, text, target)?),
+    })
+}
+
+/// Looks up the id of the name of a workflow.
+fn parse_workflow_name(names: &mut Interner, text: &str, name: &str) -> Result<usize, ParseError> {
+    if !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        Ok(names.intern(name) as usize)
+    } else {
+        Err(ParseError::in_text(text, name, "a workflow name"))
     }
 }
 
@@ -363,18 +320,135 @@ pub enum Operation {
     Smaller,
 }
 
+/// Reads the workflows and the parts that follow them after an empty line.
+pub fn parse(input: impl BufRead) -> Result<(RulezNulz, Vec<Part>), ParseError> {
+    let mut lines = aoc_core::lines(input);
+    let mut nulz_rulez = RulezNulz::new("in");
+    for line in lines.by_ref() {
+        let (n, line) = line?;
+        if line.is_empty() {
+            break;
+        }
+        let (ident, rules) =
+            parse_workflow(&mut nulz_rulez.names, &line).map_err(|e| e.on_line(n))?;
+        if nulz_rulez.insert(ident, rules).is_some() {
+            let name = &line[..line.find('{').unwrap_or(line.len())];
+            return Err(
+                ParseError::in_text(&line, name, "a workflow not defined before").on_line(n),
+            );
+        }
+    }
+    // Every workflow that is sent to has to be defined.
+    nulz_rulez.buffer.resize(nulz_rulez.names.len(), None);
+    if let Some(i) = nulz_rulez.buffer.iter().position(Option::is_none) {
+        let name = nulz_rulez.names.name(i as u32);
+        return Err(ParseError::end(format!("the workflow `{name}`")));
+    }
+    let mut parts = Vec::new();
+    for line in lines {
+        let (n, line) = line?;
+        if line.is_empty() {
+            continue;
+        }
+        parts.push(line.parse().map_err(|e: ParseError| e.on_line(n))?);
+    }
+    Ok((nulz_rulez, parts))
+}
+
+/// Parses a workflow like `px{a<2006:qkq,m>2090:A,rfg}`.
+fn parse_workflow(names: &mut Interner, line: &str) -> Result<(usize, RuleContainer), ParseError> {
+    let Some((name, rules)) = line.split_once('{') else {
+        return Err(ParseError::in_text(line, line, "`<name>{<rules>}`"));
+    };
+    let Some(rules) = rules.strip_suffix('}') else {
+        return Err(ParseError::in_text(line, rules, "rules enclosed in `{}`"));
+    };
+    let ident = parse_workflow_name(names, line, name)?;
+    let rules = RuleContainer::parse(rules, names).map_err(|e| e.within(line, rules))?;
+    Ok((ident, rules))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    /// The workflows and the parts to sort.
+    type Input = (RulezNulz, Vec<Part>);
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    /// Sums the ratings of all accepted parts.
+    fn part1((nulz_rulez, parts): &Self::Input) -> u32 {
+        parts
+            .iter()
+            .filter(|p| nulz_rulez.is_accepted(**p))
+            .map(|p| p.sum_val())
+            .sum()
+    }
+
+    /// Counts the distinct rating combinations that are accepted by the workflows.
+    fn part2((nulz_rulez, _): &Self::Input) -> usize {
+        nulz_rulez
+            .range_discovery()
+            .into_iter()
+            .map(|r| r.volume() as usize)
+            .sum()
+    }
+}
+
+#[test]
+fn test_rule_split() {
+    let rule = Rule::parse("x>10:A", &mut Interner::new()).unwrap();
+    let (matched, rest) = rule.split(all_ratings());
+    assert_eq!(
+        matched.unwrap().axis(PartValue::X as usize),
+        Interval::new(11, 4001)
+    );
+    assert_eq!(rest.unwrap().volume(), 10 * 4000_u128.pow(3));
+    let rule = Rule::parse("m<1:R", &mut Interner::new()).unwrap();
+    assert_eq!(rule.split(all_ratings()), (None, Some(all_ratings())));
+    let rule = Rule::parse("s>4000:R", &mut Interner::new()).unwrap();
+    assert_eq!(rule.split(all_ratings()), (None, Some(all_ratings())));
+}
+
+#[test]
+fn test_long_names() {
+    let input = "in{x<5:first,second}\nfirst{A}\nsecond{m>100:R,A}\n\n{x=1,m=1,a=1,s=1}\n";
+    let (nulz_rulez, parts) = parse(input.as_bytes()).unwrap();
+    assert_eq!(nulz_rulez.names.name(2), "second");
+    assert!(nulz_rulez.is_accepted(parts[0]));
+    let e = parse("in{x<5:first,A}\n".as_bytes()).unwrap_err();
+    assert_eq!(e.expected, "the workflow `first`");
+}
+
+#[test]
+fn test_parse_error() {
+    let e = parse_workflow(&mut Interner::new(), "px{a<2006:qkq,q>2090:A,rfg}").unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (15, "q"));
+    let e = "{x=787,m=2655,a=1222,s=2876x}".parse::<Part>().unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (24, "2876x"));
+    let e = parse("in{px}\npx{A}\npx{R}\n".as_bytes()).unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (3, 1, "px"));
+    let e = Rule::parse("x>4294967295:A", &mut Interner::new()).unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::new("4294967295", "a rating up to 4000").at(0, 3)
+    );
+    let e = "{x=1,m=4001,a=1,s=1}".parse::<Part>().unwrap_err();
+    assert_eq!((e.column, e.expected.as_str()), (8, "a rating up to 4000"));
+}
+
 #[test]
-fn test_set_range_val() {
-    let mut range = RangePart::new();
-    println!("{:?}", range);
-    range.set_part_value(PartValue::X, (1, 2));
-    println!("{:?}", range);
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 19114);
+    assert_eq!(Solution::part2(&input), 167409079868000);
 }
 
 #[test]
-fn test_hash() {
-    let t = "zzz";
-    println!("{}", RulezNulz::hash(&t));
-    let t = "z";
-    println!("{}", RulezNulz::hash(&t));
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(19, env!("CARGO_MANIFEST_DIR"));
 }
@@ -0,0 +1,134 @@
+use aoc_core::rng::Rng;
+use std::collections::HashSet;
+
+/// The flip-flops of a counter, which count the button presses in binary.
+const BITS: usize = 12;
+
+/// Generates a machine of up to five counters whose conjunctions have to send a high pulse at
+/// once for `rx` to receive a low one, one counter for every 20 of `size`.
+pub fn generate(size: usize, seed: u64) -> String {
+    machine(size, seed).0
+}
+
+/// The machine and the button presses needed to deliver a low pulse to `rx`.
+///
+/// Each counter is a chain of flip-flops with a conjunction watching the bits set in its
+/// period. Once they are all on, it sends a low pulse to the flip-flops of the unset bits and to
+/// the first one, which adds up to the next power of two and resets the counter to zero.
+fn machine(size: usize, seed: u64) -> (String, usize) {
+    let mut rng = Rng::new(seed);
+    let counters = (size / 20).clamp(1, 5);
+    let mut names = Names::default();
+    let last = names.next(&mut rng);
+    let mut modules = vec![format!("&{last} -> rx")];
+    let mut starts = Vec::new();
+    let mut periods = Vec::new();
+    for _ in 0..counters {
+        // Odd periods with the highest bit set, so the first flip-flop is always watched.
+        let period = rng.range(1 << (BITS - 1)..1 << BITS) as usize | 1;
+        periods.push(period);
+        let bits: Vec<String> = (0..BITS).map(|_| names.next(&mut rng)).collect();
+        let (conjunction, inverter) = (names.next(&mut rng), names.next(&mut rng));
+        let mut resets = vec![inverter.clone(), bits[0].clone()];
+        for (i, bit) in bits.iter().enumerate() {
+            let mut next: Vec<&str> = bits.get(i + 1).map(String::as_str).into_iter().collect();
+            if period >> i & 1 == 1 {
+                next.push(&conjunction);
+            } else {
+                resets.push(bit.clone());
+            }
+            rng.shuffle(&mut next);
+            modules.push(format!("%{bit} -> {}", next.join(", ")));
+        }
+        rng.shuffle(&mut resets);
+        modules.push(format!("&{conjunction} -> {}", resets.join(", ")));
+        modules.push(format!("&{inverter} -> {last}"));
+        starts.push(bits[0].clone());
+    }
+    modules.push(format!("broadcaster -> {}", starts.join(", ")));
+    rng.shuffle(&mut modules);
+    let presses = aoc_core::math::lcm_all(periods).expect("At most five periods of 12 bits");
+    (modules.join("\n") + "\n", presses)
+}
+
+/// Hands out distinct names of two lowercase letters other than `rx`.
+#[derive(Default)]
+struct Names {
+    seen: HashSet<String>,
+}
+
+impl Names {
+    fn next(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name: String = (0..2)
+                .map(|_| (b'a' + rng.index(26) as u8) as char)
+                .collect();
+            if name != "rx" && self.seen.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+    use std::collections::{HashMap, VecDeque};
+
+    /// Presses the button a thousand times and multiplies the low and high pulses sent.
+    fn pulses(text: &str) -> usize {
+        let modules: HashMap<&str, (u8, Vec<&str>)> = text
+            .lines()
+            .map(|line| {
+                let (name, outputs) = line.split_once(" -> ").unwrap();
+                let kind = name.as_bytes()[0];
+                let name = name.trim_start_matches(['%', '&']);
+                (name, (kind, outputs.split(", ").collect()))
+            })
+            .collect();
+        let mut on: HashSet<&str> = HashSet::new();
+        let mut memory: HashMap<(&str, &str), bool> = HashMap::new();
+        for (name, (_, outputs)) in &modules {
+            for output in outputs {
+                memory.insert((output, name), false);
+            }
+        }
+        let mut sent = [0; 2];
+        for _ in 0..1000 {
+            let mut queue = VecDeque::from([("button", "broadcaster", false)]);
+            while let Some((from, to, high)) = queue.pop_front() {
+                sent[usize::from(high)] += 1;
+                let Some((kind, outputs)) = modules.get(to) else {
+                    continue;
+                };
+                let send = match kind {
+                    b'%' if high => continue,
+                    b'%' => on.insert(to) || !on.remove(to),
+                    b'&' => {
+                        memory.insert((to, from), high);
+                        !memory
+                            .iter()
+                            .filter(|((m, _), _)| *m == to)
+                            .all(|(_, h)| *h)
+                    }
+                    _ => high,
+                };
+                queue.extend(outputs.iter().map(|o| (to, *o, send)));
+            }
+        }
+        sent[0] * sent[1]
+    }
+
+    for seed in 0..5 {
+        let (text, presses) = machine(100, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        // Five counters of flip-flops with odd periods, all feeding the one conjunction of `rx`.
+        let flip_flops = text.lines().filter(|l| l.starts_with('%')).count();
+        assert_eq!(flip_flops, 5 * BITS);
+        assert_eq!(text.lines().filter(|l| l.ends_with("-> rx")).count(), 1);
+        assert_eq!(presses % 2, 1);
+        assert_eq!(Solution::part1(&input), pulses(&text));
+        assert_eq!(Solution::part2(&input), presses);
+    }
+}
@@ -0,0 +1,398 @@
+mod generate;
+use aoc_core::{cycle, interner::Interner, math, ParseError, Solver};
+pub use generate::generate;
+use std::{
+    collections::{HashMap, VecDeque},
+    io::BufRead,
+};
+
+/// The most button presses to wait for an input of the conjunction feeding `rx` to send a high
+/// pulse, enough for the counters of 12 flip-flops driving them in the puzzle inputs.
+pub const MAX_PRESSES: usize = 1 << 12;
+
+#[derive(Clone, Debug)]
+pub struct Machine {
+    /// The names of the modules, their ids index `modules`.
+    names: Interner,
+    broadcast: Vec<usize>,
+    inhabited: Vec<usize>,
+    modules: Vec<Option<Module>>,
+}
+
+impl Machine {
+    pub fn new(
+        names: Interner,
+        broadcast: Vec<usize>,
+        inhabited: Vec<usize>,
+        mut modules: Vec<Option<Module>>,
+    ) -> Self {
+        let conjunction_idx: Vec<usize> = inhabited
+            .iter()
+            .copied()
+            .filter(|m| modules[*m].as_ref().unwrap().is_conjunction())
+            .collect();
+        for module_idx in inhabited.iter().copied() {
+            let module = modules[module_idx].as_ref().unwrap();
+            let next = match module {
+                Module::FlipFlop { state: _, next } => next,
+                Module::Conjunction { state: _, next } => next,
+            }
+            .clone();
+            for n in next
+                .iter()
+                .copied()
+                .filter(|idx| conjunction_idx.contains(idx))
+            {
+                let Some(Module::Conjunction { state, next: _ }) = modules[n].as_mut() else {
+                    unreachable!()
+                };
+                state.insert(module_idx, Pulse::Low);
+            }
+        }
+        Self {
+            names,
+            broadcast,
+            inhabited,
+            modules,
+        }
+    }
+
+    /// Presses the button `iterations` times and multiplies the counts of high and low pulses sent.
+    pub fn init_pulse(&mut self, pulse: Pulse, iterations: usize) -> usize {
+        let press = |machine: &Machine| {
+            let mut machine = machine.clone();
+            machine.press(pulse, |_, _| {});
+            machine
+        };
+        let cycle = cycle::find_cycle_by_key(self, press, Machine::fingerprint, iterations);
+        let presses = cycle.map_or(iterations, |c| iterations.min(c.prefix + c.period));
+        let counts: Vec<(usize, usize)> =
+            (0..presses).map(|_| self.press(pulse, |_, _| {})).collect();
+        let sum = |counts: &[(usize, usize)]| {
+            counts
+                .iter()
+                .fold((0, 0), |acc, c| (acc.0 + c.0, acc.1 + c.1))
+        };
+        let total = match cycle {
+            Some(cycle) if presses < iterations => {
+                // The presses after the prefix repeat the counts of the cycle.
+                let (prefix, period) = counts.split_at(cycle.prefix);
+                let repeats = (iterations - cycle.prefix) / cycle.period;
+                let rest = (iterations - cycle.prefix) % cycle.period;
+                let (prefix, period, rest) = (sum(prefix), sum(period), sum(&period[..rest]));
+                (
+                    prefix.0 + repeats * period.0 + rest.0,
+                    prefix.1 + repeats * period.1 + rest.1,
+                )
+            }
+            _ => sum(&counts),
+        };
+        total.0 * total.1
+    }
+
+    /// Presses the button sending `pulse` to the broadcaster and counts the high and low pulses
+    /// sent until the machine settles.
+    ///
+    /// Every pulse a module sends is handed to `observe` together with the module as it arrives.
+    fn press(&mut self, pulse: Pulse, mut observe: impl FnMut(usize, Pulse)) -> (usize, usize) {
+        use Pulse::*;
+        let mut pulse_counter = match pulse {
+            High => (1, 0),
+            Low => (0, 1),
+        };
+        let mut priority: VecDeque<(Option<usize>, usize, Pulse)> = VecDeque::new();
+        for i in self.broadcast.iter().copied() {
+            match pulse {
+                High => {
+                    pulse_counter.0 += 1;
+                }
+                Low => {
+                    pulse_counter.1 += 1;
+                }
+            };
+            priority.push_back((None, i, pulse));
+        }
+        while let Some(c_m) = priority.pop_front() {
+            if let Some(sender) = c_m.0 {
+                observe(sender, c_m.2);
+            }
+            if self.modules[c_m.1].is_none() {
+                continue;
+            }
+            match (
+                self.modules.get_mut(c_m.1).unwrap().as_mut().unwrap(),
+                c_m.2,
+            ) {
+                (Module::FlipFlop { state, next }, Pulse::Low) => {
+                    *state = !*state;
+                    if *state {
+                        pulse_counter.0 += next.len();
+                        Self::send_pulse(c_m.1, High, next, &mut priority);
+                    } else {
+                        pulse_counter.1 += next.len();
+                        Self::send_pulse(c_m.1, Low, next, &mut priority);
+                    }
+                }
+                (Module::Conjunction { state, next }, Pulse::High) => {
+                    state.insert(c_m.0.unwrap(), High);
+                    if state.iter().all(|p| *p.1 == High) {
+                        pulse_counter.1 += next.len();
+                        Self::send_pulse(c_m.1, Low, next, &mut priority);
+                    } else {
+                        pulse_counter.0 += next.len();
+                        Self::send_pulse(c_m.1, High, next, &mut priority);
+                    }
+                }
+                (Module::Conjunction { state, next }, Pulse::Low) => {
+                    state.insert(c_m.0.unwrap(), Low);
+                    pulse_counter.0 += next.len();
+                    Self::send_pulse(c_m.1, High, next, &mut priority);
+                }
+                _ => {}
+            }
+        }
+        pulse_counter
+    }
+
+    /// The conjunction that is the only module sending pulses to `rx`, `None` if there is none or
+    /// it has no inputs of its own.
+    fn rx_feeder(&self) -> Option<usize> {
+        let rx = self.names.get("rx")? as usize;
+        let mut feeders = self.inhabited.iter().copied().filter(|idx| {
+            match self.modules[*idx].as_ref().unwrap() {
+                Module::FlipFlop { state: _, next } => next.contains(&rx),
+                Module::Conjunction { state: _, next } => next.contains(&rx),
+            }
+        });
+        match (feeders.next(), feeders.next()) {
+            (Some(f), None) => match self.modules[f].as_ref().unwrap() {
+                Module::Conjunction { state, next: _ } if !state.is_empty() => Some(f),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Counts the button presses until `rx` receives a low pulse.
+    ///
+    /// The conjunction feeding `rx` sends it a low pulse once all of its inputs sent it a high
+    /// pulse during the same press. Each input is driven by a counter sending it on every multiple
+    /// of its period, so they first line up after the least common multiple of their periods.
+    ///
+    /// # Panics
+    /// If an input of the conjunction sends no high pulse within [`MAX_PRESSES`] presses.
+    pub fn run_til_finish(&mut self) -> usize {
+        let goal = self.rx_feeder().expect("A conjunction feeding rx");
+        let inputs: Vec<usize> = self
+            .inhabited
+            .iter()
+            .copied()
+            .filter(|idx| match self.modules[*idx].as_ref().unwrap() {
+                Module::FlipFlop { state: _, next } => next.contains(&goal),
+                Module::Conjunction { state: _, next } => next.contains(&goal),
+            })
+            .collect();
+        let mut periods: Vec<Option<usize>> = vec![None; inputs.len()];
+        for presses in 1..=MAX_PRESSES {
+            self.press(Pulse::Low, |sender, pulse| {
+                if let Some(i) = inputs.iter().position(|m| *m == sender) {
+                    if pulse == Pulse::High {
+                        periods[i].get_or_insert(presses);
+                    }
+                }
+            });
+            if let Some(periods) = periods.iter().copied().collect::<Option<Vec<usize>>>() {
+                return math::lcm_all(periods).expect("The button presses fit into a usize");
+            }
+        }
+        panic!(
+            "An input of the conjunction feeding rx sends no high pulse in {MAX_PRESSES} presses"
+        )
+    }
+
+    fn send_pulse(
+        sender: usize,
+        pulse: Pulse,
+        targets: &[usize],
+        list: &mut VecDeque<(Option<usize>, usize, Pulse)>,
+    ) {
+        for target in targets.iter() {
+            list.push_back((Some(sender), *target, pulse))
+        }
+    }
+
+    /// The memory of all modules, the state of the flip-flops and the last pulses remembered by
+    /// the conjunctions.
+    fn fingerprint(&self) -> Vec<bool> {
+        let mut state = Vec::new();
+        for i in &self.inhabited {
+            match self.modules[*i].as_ref().unwrap() {
+                Module::FlipFlop { state: s, next: _ } => state.push(*s),
+                Module::Conjunction { state: s, next: _ } => state.extend(
+                    self.inhabited
+                        .iter()
+                        .filter_map(|i| s.get(i))
+                        .map(|p| *p == Pulse::High),
+                ),
+            }
+        }
+        state
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Module {
+    FlipFlop {
+        state: bool,
+        next: Vec<usize>,
+    },
+    Conjunction {
+        state: HashMap<usize, Pulse>,
+        next: Vec<usize>,
+    },
+}
+
+impl Module {
+    fn is_conjunction(&self) -> bool {
+        match self {
+            Module::FlipFlop { state: _, next: _ } => false,
+            Module::Conjunction { state: _, next: _ } => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+fn parse_machine(input: impl BufRead) -> Result<Machine, ParseError> {
+    let mut broadcast: Vec<usize> = Vec::new();
+    let mut inhabited: Vec<usize> = Vec::new();
+    let mut modules: Vec<Option<Module>> = Vec::new();
+    let mut names = Interner::new();
+    for line in aoc_core::lines(input) {
+        let (n, line) = line?;
+        let on_line = |e: ParseError| e.on_line(n);
+        let Some((src, dest)) = line.split_once("->") else {
+            return Err(ParseError::in_text(&line, &line, "`<module> -> <outputs>`").on_line(n));
+        };
+        let src = src.trim();
+        let next = dest
+            .split(", ")
+            .filter(|s| !s.trim().is_empty())
+            .map(|d| parse_module_name(&mut names, &line, d))
+            .collect::<Result<Vec<usize>, _>>()
+            .map_err(on_line)?;
+        match src.chars().next() {
+            _ if src == "broadcaster" => broadcast = next,
+            Some('%') => {
+                let idx = parse_module_name(&mut names, &line, &src[1..]).map_err(on_line)?;
+                inhabited.push(idx);
+                modules.resize(names.len(), None);
+                modules[idx] = Some(Module::FlipFlop { state: false, next });
+            }
+            Some('&') => {
+                let idx = parse_module_name(&mut names, &line, &src[1..]).map_err(on_line)?;
+                inhabited.push(idx);
+                modules.resize(names.len(), None);
+                modules[idx] = Some(Module::Conjunction {
+                    state: HashMap::new(),
+                    next,
+                });
+            }
+            _ => {
+                let expected = "`broadcaster`, a flip-flop or a conjunction module";
+                return Err(ParseError::in_text(&line, src, expected).on_line(n));
+            }
+        }
+    }
+    // Modules that only receive pulses have no entry of their own.
+    modules.resize(names.len(), None);
+    Ok(Machine::new(names, broadcast, inhabited, modules))
+}
+
+/// Looks up the id of the name of a module.
+fn parse_module_name(names: &mut Interner, text: &str, name: &str) -> Result<usize, ParseError> {
+    let name = name.trim();
+    if !name.is_empty() && !name.contains(char::is_whitespace) {
+        Ok(names.intern(name) as usize)
+    } else {
+        Err(ParseError::in_text(text, name, "a module name"))
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Machine;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    /// Reads the machine, which has to deliver pulses to `rx` through a single conjunction.
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        let machine = parse_machine(input)?;
+        match machine.rx_feeder() {
+            Some(_) => Ok(machine),
+            None => Err(ParseError::end("a single conjunction sending to `rx`")),
+        }
+    }
+
+    /// Multiplies the low and high pulses sent after pushing the button 1000 times.
+    fn part1(machine: &Self::Input) -> usize {
+        machine.clone().init_pulse(Pulse::Low, 1000)
+    }
+
+    /// Counts the button presses needed to deliver a single low pulse to `rx`.
+    fn part2(machine: &Self::Input) -> usize {
+        machine.clone().run_til_finish()
+    }
+}
+
+#[test]
+fn test_long_names() {
+    // The flip-flop sends a high pulse on every odd and a low pulse on every even press.
+    let machine = parse_machine("broadcaster -> first\n%first -> output\n".as_bytes()).unwrap();
+    assert_eq!(machine.names.name(1), "output");
+    assert_eq!(Solution::part1(&machine), 500 * 2500);
+}
+
+#[test]
+fn test_sample() {
+    // The samples have no `rx` for the second part.
+    let machine = parse_machine(include_str!("../sample").as_bytes()).unwrap();
+    assert_eq!(Solution::part1(&machine), 32000000);
+    let machine = parse_machine(include_str!("../sample2").as_bytes()).unwrap();
+    assert_eq!(Solution::part1(&machine), 11687500);
+}
+
+#[test]
+fn test_parse_error() {
+    let expected = ParseError::end("a single conjunction sending to `rx`");
+    assert_eq!(Solution::parse_str("").unwrap_err(), expected);
+    let e = Solution::parse_str(include_str!("../sample")).unwrap_err();
+    assert_eq!(e, expected);
+    let e = Solution::parse_str("broadcaster -> a\n%a -> rx\n").unwrap_err();
+    assert_eq!(e, expected);
+    let e = Solution::parse_str("broadcaster -> a, b\n&a -> rx\n&b -> rx\n").unwrap_err();
+    assert_eq!(e, expected);
+    let e = Solution::parse_str("broadcaster -> b\n%b -> b\n&a -> rx\n").unwrap_err();
+    assert_eq!(e, expected);
+}
+
+#[test]
+fn test_silent_input() {
+    let machine = Solution::parse_str("broadcaster -> b\n%b -> a\n&a -> rx\n").unwrap();
+    assert_eq!(Solution::part2(&machine), 1);
+    // The flip-flop feeding the conjunction never receives a pulse.
+    let input = "broadcaster -> f\n%f -> f\n%b -> a\n&a -> rx\n";
+    let machine = Solution::parse_str(input).unwrap();
+    let result = std::panic::catch_unwind(|| Solution::part2(&machine));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(20, env!("CARGO_MANIFEST_DIR"));
+}
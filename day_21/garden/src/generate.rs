@@ -0,0 +1,97 @@
+use aoc_core::{rng::Rng, search};
+use grid::{Grid, Position};
+
+/// The width and height of the garden.
+///
+/// The 26501365 steps of the elf only end at the edge of a copy of the map for this width, as
+/// 26501365 is 202300 times 131 plus the 65 steps from the start to the edge.
+const SIDE: usize = 131;
+
+/// Generates a garden of 131 by 131 tiles with the start in the middle, `size` in a thousand
+/// tiles being rocks, but at most a quarter of them.
+///
+/// Like in the puzzle input the row and the column of the start, the edges and a diamond shaped
+/// lane of the tiles 65 steps away from the start are free of rocks.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let rocks = size.min(250) as u64;
+    let half = SIDE / 2;
+    let mut garden = Grid::filled(SIDE, SIDE, b'.');
+    let middle = Position::new(half, half);
+    for p in garden.positions().collect::<Vec<_>>() {
+        let lanes = [p.row, p.col]
+            .iter()
+            .any(|c| [0, half, SIDE - 1].contains(c));
+        let steps = p.distance(middle);
+        if !lanes && steps.abs_diff(half) > 1 && rng.chance(rocks, 1000) {
+            garden[p] = b'#';
+        }
+    }
+    // The plots of whole copies of the map are counted by their steps from the start, so every
+    // plot has to be reachable without a detour from the start as well as from where the elf
+    // enters a copy, the corners and the middles of the edges. Clear the rocks in the way.
+    let end = SIDE - 1;
+    let entries = [0, half, end].map(|r| [0, half, end].map(|c| Position::new(r, c)));
+    loop {
+        let mut cleared = false;
+        for &entry in entries.iter().flatten() {
+            let steps = |p: Position| p.distance(entry);
+            let plots = |p: &Position| garden.neighbours(*p).filter(|n| garden[*n] != b'#');
+            let rocks: Vec<Position> = search::bfs_distances(entry, plots)
+                .iter()
+                .filter(|(p, s)| *s > steps(**p))
+                .filter_map(|(p, _)| {
+                    garden
+                        .neighbours(*p)
+                        .find(|n| garden[*n] == b'#' && steps(*n) < steps(*p))
+                })
+                .collect();
+            for rock in rocks {
+                garden[rock] = b'.';
+                cleared = true;
+            }
+        }
+        if !cleared {
+            break;
+        }
+    }
+    garden[middle] = b'S';
+    garden.to_string()
+}
+
+#[test]
+fn test_generate() {
+    use crate::{GardenTile, Solution};
+    use aoc_core::Solver;
+
+    for seed in 0..3 {
+        let input = Solution::parse_str(&generate(100, seed)).unwrap();
+        let (garden, start) = &input;
+        // Walk the repeated map itself for two copies of it in every direction.
+        let limit = SIDE / 2 + 2 * SIDE;
+        let (side, start) = (SIDE as i64, (start.row as i64, start.col as i64));
+        let plots = |&(r, c): &(i64, i64)| {
+            [(r - 1, c), (r + 1, c), (r, c - 1), (r, c + 1)]
+                .into_iter()
+                .filter(|(r, c)| {
+                    let tile =
+                        Position::new(r.rem_euclid(side) as usize, c.rem_euclid(side) as usize);
+                    let distance = r.abs_diff(start.0) + c.abs_diff(start.1);
+                    garden[tile] == GardenTile::Plot && distance as usize <= limit
+                })
+        };
+        let distances = search::bfs_distances(start, plots);
+        let reachable = |steps: usize| {
+            distances
+                .iter()
+                .filter(|(_, s)| *s <= steps && s % 2 == steps % 2)
+                .count()
+        };
+        assert_eq!(Solution::part1(&input), reachable(64));
+        // The plots reachable grow quadratically with the copies of the map walked through.
+        let [a, b, c] = [0, 1, 2].map(|n| reachable(SIDE / 2 + n * SIDE));
+        let n = 26501365 / SIDE;
+        let expected = a + n * (b - a) + n * (n - 1) / 2 * (c - 2 * b + a);
+        assert_eq!(Solution::part2(&input), expected);
+    }
+}
@@ -0,0 +1,138 @@
+mod generate;
+mod render;
+use aoc_core::{search, ParseError, Solver};
+pub use generate::generate;
+use grid::{Grid, Position, Tile};
+pub use render::render;
+use std::io::BufRead;
+
+#[derive(PartialEq, Eq, Clone, Copy, Tile)]
+#[tile(expected = "a garden plot or a rock")]
+pub enum GardenTile {
+    #[tile(b'.')]
+    Plot,
+    #[tile(b'#')]
+    Rock,
+}
+
+/// The garden map and the starting position.
+pub type Garden = (Grid<GardenTile>, Position);
+
+/// Reads the garden map and returns it with the starting position.
+pub fn parse_garden(input: impl BufRead) -> Result<Garden, ParseError> {
+    let field = Grid::parse(input, |b| match b {
+        b'S' => Ok(b),
+        _ => GardenTile::parse(b).map(|_| b),
+    })?;
+    let start = field
+        .position(|b| *b == b'S')
+        .ok_or(ParseError::end("the starting position `S`"))?;
+    // The start is a garden plot as well.
+    let field = field.map(|b| GardenTile::from_byte(*b).unwrap_or(GardenTile::Plot));
+    Ok((field, start))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_garden(input)
+    }
+
+    /// Counts the garden plots reachable in exactly 64 steps.
+    fn part1((field, start): &Self::Input) -> usize {
+        run_maze_nulz(field, *start, 64)
+    }
+
+    /// Counts the garden plots reachable in exactly 26501365 steps on the infinitely repeating map.
+    fn part2((field, start): &Self::Input) -> usize {
+        run_maze_nulz(field, *start, 26501365)
+    }
+}
+
+pub fn run_maze_nulz(maze: &Grid<GardenTile>, start: Position, step_limit: usize) -> usize {
+    let cols = maze.width();
+    let plots = |p: &Position| maze.neighbours(*p).filter(|n| maze[*n] == GardenTile::Plot);
+    let mut counting = maze.map(|_| usize::MAX);
+    for (p, s) in search::bfs_distances(start, plots).iter() {
+        if s <= step_limit {
+            counting[*p] = s;
+        }
+    }
+    let is_even = step_limit.is_multiple_of(2);
+    let half = cols / 2;
+    // Number of maps to consider
+    let n = (step_limit.saturating_sub(half)) / cols;
+    // The number of odd / even maps in n
+    let (odds, evens) = if n.is_multiple_of(2) == is_even {
+        let odds = n.pow(2);
+        let evens = (n + 1).pow(2);
+        (odds, evens)
+    } else {
+        let odds = (n + 1).pow(2);
+        let evens = n.pow(2);
+        (odds, evens)
+    };
+    // Sum of all odd tiles in odd maps in n
+    let o = odds
+        * counting
+            .iter()
+            .map(|(_, s)| *s)
+            .filter(|s| *s != usize::MAX && s % 2 != 0)
+            .count();
+    // Sum of all even tiles in even maps in n
+    let e = evens
+        * counting
+            .iter()
+            .map(|(_, s)| *s)
+            .filter(|s| *s != usize::MAX && s % 2 == 0)
+            .count();
+    let steps_last = step_limit % cols;
+    // Since we cannot reach all tiles in the outermost maps we need to subtract them
+    let outside = if is_even {
+        (n + 1)
+            * counting
+                .iter()
+                .map(|(_, s)| *s)
+                .filter(|s| *s != usize::MAX && s % 2 == 0 && *s > steps_last)
+                .count()
+    } else {
+        (n + 1)
+            * counting
+                .iter()
+                .map(|(_, s)| *s)
+                .filter(|s| *s != usize::MAX && s % 2 != 0 && *s > steps_last)
+                .count()
+    };
+    // Since we do not consider some maps we need to consider their respective elements we can
+    // reach
+    let inside = if is_even {
+        n * counting
+            .iter()
+            .map(|(_, s)| *s)
+            .filter(|s| *s != usize::MAX && s % 2 != 0 && *s > steps_last)
+            .count()
+    } else {
+        n * counting
+            .iter()
+            .map(|(_, s)| *s)
+            .filter(|s| *s != usize::MAX && s % 2 == 0 && *s > steps_last)
+            .count()
+    };
+    e + o - outside + inside
+}
+
+#[test]
+fn test_sample() {
+    let (field, start) = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(run_maze_nulz(&field, start, 6), 16);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(21, env!("CARGO_MANIFEST_DIR"));
+}
@@ -0,0 +1,40 @@
+use crate::{Garden, GardenTile};
+use aoc_core::search;
+use grid::{Canvas, Position, Rgb};
+
+/// Draws the garden plots the elf can reach in exactly `steps` steps with `O` like the puzzle.
+pub fn reachable((field, start): &Garden, steps: usize) -> Canvas {
+    let plots = |p: &Position| {
+        field
+            .neighbours(*p)
+            .filter(|n| field[*n] == GardenTile::Plot)
+    };
+    // Plots reached in fewer steps of the same parity can be returned to by stepping back and
+    // forth.
+    let reached: Vec<Position> = search::bfs_distances(*start, plots)
+        .iter()
+        .filter(|(_, s)| *s <= steps && s % 2 == steps % 2)
+        .map(|(p, _)| *p)
+        .collect();
+    let mut canvas = Canvas::new(field);
+    canvas.draw([*start], b'S');
+    canvas
+        .paint(reached.iter().copied(), Rgb::GREEN)
+        .draw(reached, b'O');
+    canvas
+}
+
+/// Draws the garden plots reachable in the 64 steps of part one.
+pub fn render(garden: &Garden) -> Canvas {
+    reachable(garden, 64)
+}
+
+#[test]
+fn test_render() {
+    use aoc_core::Solver;
+
+    let garden = crate::Solution::parse_str(include_str!("../sample")).unwrap();
+    let text = reachable(&garden, 6).text();
+    assert_eq!(text.bytes().filter(|b| *b == b'O').count(), 16);
+    assert_eq!(text.lines().nth(3), Some(".O#O#O.O#.."));
+}
@@ -0,0 +1,93 @@
+use aoc_core::rng::Rng;
+use std::collections::HashSet;
+
+/// The width and depth of the stack of bricks, like in the puzzle input.
+const SIDE: u64 = 10;
+
+/// Generates a snapshot of `size` falling bricks of one to four cubes that do not overlap.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    // Leave enough room for the bricks to take up only a fifth of the space.
+    let height = size as u64 / 5 + 5;
+    let mut taken = HashSet::new();
+    let mut bricks = Vec::new();
+    while bricks.len() < size {
+        let start = [
+            rng.range(0..SIDE),
+            rng.range(0..SIDE),
+            rng.range(1..height + 1),
+        ];
+        let mut end = start;
+        let axis = rng.index(3);
+        end[axis] += rng.range(0..4);
+        if axis < 2 && end[axis] >= SIDE {
+            continue;
+        }
+        let cubes: Vec<[u64; 3]> = (start[axis]..=end[axis])
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] = i;
+                cube
+            })
+            .collect();
+        if cubes.iter().any(|c| taken.contains(c)) {
+            continue;
+        }
+        taken.extend(cubes);
+        bricks.push(format!(
+            "{},{},{}~{},{},{}\n",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        ));
+    }
+    bricks.concat()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// Lets the bricks fall and returns how many of them moved.
+    fn settle(bricks: &mut [[usize; 6]]) -> usize {
+        bricks.sort_by_key(|b| b[2]);
+        let mut heights = [[0; SIDE as usize]; SIDE as usize];
+        let mut moved = 0;
+        for b in bricks {
+            let footprint: Vec<(usize, usize)> = (b[0]..=b[3])
+                .flat_map(|x| (b[1]..=b[4]).map(move |y| (x, y)))
+                .collect();
+            let floor = footprint.iter().map(|&(x, y)| heights[x][y]).max().unwrap();
+            if floor + 1 < b[2] {
+                moved += 1;
+                (b[5], b[2]) = (b[5] - (b[2] - floor - 1), floor + 1);
+            }
+            for (x, y) in footprint {
+                heights[x][y] = b[5];
+            }
+        }
+        moved
+    }
+
+    for seed in 0..5 {
+        let text = generate(60, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        let mut bricks: Vec<[usize; 6]> = text
+            .lines()
+            .map(|l| {
+                let values: Vec<usize> = l.split([',', '~']).map(|v| v.parse().unwrap()).collect();
+                values.try_into().unwrap()
+            })
+            .collect();
+        settle(&mut bricks);
+        let falling: Vec<usize> = (0..bricks.len())
+            .map(|i| {
+                let mut rest = bricks.clone();
+                rest.remove(i);
+                settle(&mut rest)
+            })
+            .collect();
+        let safe = falling.iter().filter(|f| **f == 0).count();
+        assert_eq!(Solution::part1(&input), safe);
+        assert_eq!(Solution::part2(&input), falling.iter().sum::<usize>());
+    }
+}
@@ -0,0 +1,272 @@
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use itertools::Itertools;
+use std::{
+    collections::{HashSet, VecDeque},
+    io::BufRead,
+};
+
+#[derive(Clone, Debug)]
+pub struct Cave {
+    stones: Vec<Stone>,
+    inner: Vec<Vec<Vec<usize>>>,
+}
+
+impl Cave {
+    pub fn new(x: usize, y: usize, z: usize, mut stones: Vec<Stone>) -> Self {
+        // An empty plane above the highest brick, so looking above a brick stays in bounds.
+        let mut cave = vec![vec![vec![0; x]; y]; z + 1];
+        Cave::let_stones_fall(&mut stones, &mut cave);
+        Self {
+            stones,
+            inner: cave,
+        }
+    }
+    /// Reads the snapshot of falling bricks and lets them settle.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, ParseError> {
+        let (mut max_x, mut max_y, mut max_z) = (0, 0, 0);
+        let mut stones: Vec<Stone> = Vec::new();
+        for line in aoc_core::lines(reader) {
+            let (n, line) = line?;
+            let Some((start, end)) = line.split_once('~') else {
+                let expected = "two corners of a brick seperated by `~`";
+                return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+            };
+            let start_values = parse_corner(&line, start).map_err(|e| e.on_line(n))?;
+            let end_values = parse_corner(&line, end).map_err(|e| e.on_line(n))?;
+            if start_values[2] == 0 {
+                return Err(
+                    ParseError::in_text(&line, start, "a brick above the ground").on_line(n),
+                );
+            }
+            if start_values.iter().zip(&end_values).any(|(s, e)| s > e) {
+                let expected = "a start corner not past the end corner";
+                return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+            }
+            max_x = max_x.max(end_values[0] + 1);
+            max_y = max_y.max(end_values[1] + 1);
+            max_z = max_z.max(end_values[2] + 1);
+            stones.push(Stone::new(
+                (start_values[0], end_values[0]),
+                (start_values[1], end_values[1]),
+                (start_values[2], end_values[2]),
+            ));
+        }
+        Ok(Cave::new(max_x, max_y, max_z, stones))
+    }
+
+    /// Sorts stones by z index and then lets them come to rest in order.
+    /// After coming to rest the cells in the cave will set to be occupied
+    /// by the stone. The stone will reference the data in the cave it occupies.
+    fn let_stones_fall(stones: &mut [Stone], cave: &mut [Vec<Vec<usize>>]) {
+        stones.sort();
+        for (i, stone) in stones.iter_mut().enumerate() {
+            let i = i + 1;
+            loop {
+                if stone.z.0 == 1 {
+                    break;
+                }
+                if cave[stone.z.0 - 1][stone.y.0..=stone.y.1]
+                    .iter()
+                    .flat_map(|z| z[stone.x.0..=stone.x.1].iter())
+                    .any(|v| *v != 0)
+                {
+                    break;
+                }
+                stone.z.0 -= 1;
+                stone.z.1 -= 1;
+            }
+
+            for plane in &mut cave[stone.z.0..=stone.z.1] {
+                for row in &mut plane[stone.y.0..=stone.y.1] {
+                    row[stone.x.0..=stone.x.1].fill(i);
+                }
+            }
+        }
+    }
+
+    pub fn count_deletable_stones(&self) -> usize {
+        self.stones.len()
+            - self
+                .stones
+                .iter()
+                .fold(HashSet::new(), |mut acc: HashSet<usize>, stone| {
+                    let x: HashSet<usize> = self.inner[stone.z.0 - 1][stone.y.0..=stone.y.1]
+                        .iter()
+                        .flat_map(|z| z[stone.x.0..=stone.x.1].iter())
+                        .filter(|c| **c != 0)
+                        .copied()
+                        .collect();
+                    if x.len() == 1 {
+                        acc.extend(x.iter());
+                    }
+                    acc
+                })
+                .len()
+    }
+
+    pub fn max_chain_reaction(&self) -> usize {
+        // The index of all stones that have an effect when removed.
+        let idx: Vec<usize> = self
+            .stones
+            .iter()
+            .fold(HashSet::new(), |mut acc: HashSet<usize>, stone| {
+                let x: HashSet<usize> = self.inner[stone.z.0 - 1][stone.y.0..=stone.y.1]
+                    .iter()
+                    .flat_map(|z| z[stone.x.0..=stone.x.1].iter())
+                    .filter(|c| **c != 0)
+                    .copied()
+                    .collect();
+                if x.len() == 1 {
+                    acc.extend(x.iter());
+                }
+                acc
+            })
+            .into_iter()
+            .collect();
+        let mut destruction: usize = 0;
+        for id in idx.into_iter().rev() {
+            let mut prio: VecDeque<usize> = VecDeque::new();
+            let mut power = HashSet::new();
+            let mut depend = 0;
+            power.insert(id);
+            power.insert(0);
+            let stone = self.stones[id - 1];
+            for above in self.inner[stone.z.1 + 1][stone.y.0..=stone.y.1]
+                .iter()
+                .flat_map(|z| z[stone.x.0..=stone.x.1].iter())
+                .filter(|c| **c != 0)
+                .unique()
+            {
+                let candidate = self.stones[above - 1];
+                if self.inner[candidate.z.0 - 1][candidate.y.0..=candidate.y.1]
+                    .iter()
+                    .flat_map(|z| z[candidate.x.0..=candidate.x.1].iter())
+                    .all(|v| power.contains(v))
+                {
+                    prio.push_back(*above);
+                }
+            }
+
+            while let Some(p) = prio.pop_front() {
+                let stone = self.stones[p - 1];
+                depend += 1;
+                power.insert(p);
+                for above in self.inner[stone.z.1 + 1][stone.y.0..=stone.y.1]
+                    .iter()
+                    .flat_map(|z| z[stone.x.0..=stone.x.1].iter())
+                    .filter(|c| **c != 0)
+                    .unique()
+                {
+                    let candidate = self.stones[above - 1];
+                    if self.inner[candidate.z.0 - 1][candidate.y.0..=candidate.y.1]
+                        .iter()
+                        .flat_map(|z| z[candidate.x.0..=candidate.x.1].iter())
+                        .all(|v| power.contains(v))
+                    {
+                        prio.push_back(*above);
+                    }
+                }
+            }
+            destruction += depend;
+        }
+        destruction
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stone {
+    x: (usize, usize),
+    y: (usize, usize),
+    z: (usize, usize),
+}
+
+impl Stone {
+    pub fn new(x: (usize, usize), y: (usize, usize), z: (usize, usize)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Ord for Stone {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.z.0.cmp(&other.z.0)
+    }
+}
+
+impl PartialOrd for Stone {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parses a corner of a brick like `1,0,1`.
+fn parse_corner(line: &str, corner: &str) -> Result<[usize; 3], ParseError> {
+    let values = corner
+        .split(',')
+        .map(|v| parse_token(line, v, "a coordinate"))
+        .collect::<Result<Vec<usize>, _>>()?;
+    values
+        .try_into()
+        .map_err(|_| ParseError::in_text(line, corner, "three coordinates"))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Cave;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        Cave::from_reader(input)
+    }
+
+    /// Counts the bricks that can be disintegrated without any other brick falling.
+    fn part1(cave: &Self::Input) -> usize {
+        cave.count_deletable_stones()
+    }
+
+    /// Sums the number of bricks that would fall for every disintegrated brick.
+    fn part2(cave: &Self::Input) -> usize {
+        cave.max_chain_reaction()
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 5);
+    assert_eq!(Solution::part2(&input), 7);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(22, env!("CARGO_MANIFEST_DIR"));
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Solution::parse_str("0,0,0~0,0,0").unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (1, "a brick above the ground")
+    );
+    let e = Solution::parse_str("1,0,1\n1,0,1~0,0,1").unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (1, "two corners of a brick seperated by `~`")
+    );
+    let e = Solution::parse_str("1,0,1~1,0,1\n1,0,1~0,0,1").unwrap_err();
+    assert_eq!(
+        (e.line, e.expected.as_str()),
+        (2, "a start corner not past the end corner")
+    );
+}
+
+#[test]
+fn test_top_brick() {
+    let input = Solution::parse_str("1,1,1~1,1,1\n1,1,2~1,1,2").unwrap();
+    assert_eq!(Solution::part1(&input), 1);
+    assert_eq!(Solution::part2(&input), 1);
+}
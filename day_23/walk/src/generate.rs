@@ -0,0 +1,122 @@
+use aoc_core::rng::Rng;
+use grid::{Grid, Position};
+use std::ops::Range;
+
+/// The fewest tiles from one junction to the next, leaving room for a slope at both ends of the
+/// trail between them.
+const GAP: usize = 4;
+
+/// Generates a `size` by `size` map of trails between a lattice of up to six by six junctions.
+///
+/// The hike starts in the top left corner and leads down to the first junction, from where
+/// straight trails lead to the neighbouring junctions, the last one leading down to the target
+/// in the bottom row. Slopes at both ends of the trails make them downhill to the right and
+/// downwards, like in the puzzle input.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let size = size.max(5);
+    // The junctions leave the top two rows and the bottom two rows to the start and the target.
+    let len = ((size - 5) / GAP + 1).min(6);
+    let rows = spread(&mut rng, len, 2..size - 2, false);
+    let columns = spread(&mut rng, len, 1..size - 1, true);
+    let mut map = Grid::filled(size, size, b'#');
+    for row in 0..=rows[0] {
+        map[Position::new(row, 1)] = b'.';
+    }
+    for row in rows[len - 1]..size {
+        map[Position::new(row, columns[len - 1])] = b'.';
+    }
+    for &row in &rows {
+        for column in columns[0]..=columns[len - 1] {
+            map[Position::new(row, column)] = b'.';
+        }
+        for pair in columns.windows(2) {
+            map[Position::new(row, pair[0] + 1)] = b'>';
+            map[Position::new(row, pair[1] - 1)] = b'>';
+        }
+    }
+    for &column in &columns {
+        for row in rows[0]..=rows[len - 1] {
+            map[Position::new(row, column)] = b'.';
+        }
+        for pair in rows.windows(2) {
+            map[Position::new(pair[0] + 1, column)] = b'v';
+            map[Position::new(pair[1] - 1, column)] = b'v';
+        }
+    }
+    map.to_string()
+}
+
+/// `len` random positions in `range` at least [`GAP`] apart, the first one being the start of
+/// the range if it is `fixed`.
+fn spread(rng: &mut Rng, len: usize, range: Range<usize>, fixed: bool) -> Vec<usize> {
+    let slack = (range.end - 1 - range.start - GAP * (len - 1)) as u64;
+    let mut extra: Vec<usize> = (0..len)
+        .map(|i| match i {
+            0 if fixed => 0,
+            _ => rng.range(0..slack + 1) as usize,
+        })
+        .collect();
+    extra.sort_unstable();
+    extra
+        .into_iter()
+        .enumerate()
+        .map(|(i, e)| range.start + GAP * i + e)
+        .collect()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// The most steps from `pos` to the bottom row through the tiles not `visited` yet, walking
+    /// slopes like paths.
+    fn longest(maze: &[&[u8]], pos: (usize, usize), visited: &mut [Vec<bool>]) -> Option<usize> {
+        if pos.0 == maze.len() - 1 {
+            return Some(0);
+        }
+        visited[pos.0][pos.1] = true;
+        let (r, c) = pos;
+        let neighbours = [
+            (r.wrapping_sub(1), c),
+            (r + 1, c),
+            (r, c.wrapping_sub(1)),
+            (r, c + 1),
+        ];
+        let steps = neighbours
+            .into_iter()
+            .filter(|&(r, c)| {
+                maze.get(r)
+                    .and_then(|row| row.get(c))
+                    .is_some_and(|t| *t != b'#')
+            })
+            .filter_map(|(r, c)| {
+                if visited[r][c] {
+                    None
+                } else {
+                    Some(1 + longest(maze, (r, c), visited)?)
+                }
+            })
+            .max();
+        visited[pos.0][pos.1] = false;
+        steps
+    }
+
+    for seed in 0..5 {
+        let text = generate(20, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        // Every hike downhill takes the same number of steps, the ones down to the bottom row and
+        // the ones from the first to the last column.
+        let target = text.lines().last().unwrap().find('.').unwrap();
+        assert_eq!(Solution::part1(&input), 19 + target - 1);
+        let maze: Vec<&[u8]> = text.lines().map(str::as_bytes).collect();
+        let mut visited = vec![vec![false; 20]; 20];
+        assert_eq!(
+            Some(Solution::part2(&input)),
+            longest(&maze, (0, 1), &mut visited)
+        );
+    }
+    let input = Solution::parse_str(&generate(5, 0)).unwrap();
+    assert_eq!((Solution::part1(&input), Solution::part2(&input)), (4, 4));
+}
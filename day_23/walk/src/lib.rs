@@ -0,0 +1,258 @@
+mod generate;
+mod render;
+use aoc_core::{search, ParseError, Solver};
+pub use generate::generate;
+use grid::{Direction, Grid, Position, Tile};
+pub use render::render;
+use std::io::BufRead;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Tile)]
+#[tile(expected = "a path, forest or slope")]
+pub enum Field {
+    #[tile(b'.')]
+    Path,
+    #[tile(b'#')]
+    Forest,
+    /// A slope given by the arrow of the direction it leads to
+    Slope(Direction),
+}
+
+/// Parses the map, which has to lead down the slopes from a path in its top row to a path in its
+/// bottom row.
+fn parse_maze(input: impl BufRead) -> Result<Grid<Field>, ParseError> {
+    let maze = Grid::parse(input, Field::parse)?;
+    let row_error = |row: usize, expected| {
+        let tiles: String = maze.row(row).iter().map(|f| f.to_byte() as char).collect();
+        ParseError::new(tiles, expected).at(row + 1, 1)
+    };
+    let bottom = maze.height() - 1;
+    let Some(start) = find_path(&maze, 0) else {
+        return Err(row_error(0, "a path in the top row"));
+    };
+    if bottom == 0 {
+        return Err(ParseError::end("a bottom row").on_line(2));
+    }
+    let Some(target) = find_path(&maze, bottom) else {
+        return Err(row_error(bottom, "a path in the bottom row"));
+    };
+    // A hike following the slopes can also be walked when they are regular paths.
+    if search::bfs(start, |p| moves(&maze, *p, true), |p| *p == target).is_none() {
+        return Err(row_error(bottom, "a path reachable down the slopes"));
+    }
+    Ok(maze)
+}
+
+/// The path tile in the top or bottom `row`, where hikes start or end.
+fn find_path(maze: &Grid<Field>, row: usize) -> Option<Position> {
+    let col = maze.row(row).iter().position(|f| *f == Field::Path)?;
+    Some(Position::new(row, col))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Grid<Field>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_maze(input)
+    }
+
+    /// Finds the longest hike that follows the icy slopes downhill.
+    fn part1(maze: &Self::Input) -> usize {
+        longest_hike(maze, true).expect("A hike to the target")
+    }
+
+    /// Finds the longest hike when slopes can be walked like regular paths.
+    fn part2(maze: &Self::Input) -> usize {
+        longest_hike(maze, false).expect("A hike to the target")
+    }
+}
+
+/// The tiles that can be walked to from `pos`, only sliding down slopes if they are `slippery`.
+fn moves(maze: &Grid<Field>, pos: Position, slippery: bool) -> Vec<Position> {
+    match maze[pos] {
+        Field::Slope(d) if slippery => maze.step(pos, d).into_iter().collect(),
+        _ => Direction::ALL
+            .into_iter()
+            .filter_map(|d| Some((maze.step(pos, d)?, d)))
+            // Slopes pointing back at the current tile can not be climbed.
+            .filter(|(n, d)| {
+                maze[*n] != Field::Forest && !(slippery && maze[*n] == Field::Slope(d.reverse()))
+            })
+            .map(|(n, _)| n)
+            .collect(),
+    }
+}
+
+/// The junctions where trails branch and the trails between them.
+struct Network {
+    /// The junctions, starting with the start and the target
+    junctions: Vec<Position>,
+    /// The index of the junction on every tile
+    index: Grid<Option<usize>>,
+    /// The longest trail from every junction to each of the junctions it leads to
+    trails: Vec<Vec<Trail>>,
+}
+
+/// A trail from a junction to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Trail {
+    to: usize,
+    steps: usize,
+    /// The first tile of the trail after the junction it leaves
+    first: Position,
+}
+
+/// Finds the longest hike from the start to the target that does not visit a tile twice.
+///
+/// The maze is reduced to the junctions where trails branch, connected by the longest trails
+/// between them, and every hike through them is tried.
+pub fn longest_hike(maze: &Grid<Field>, slippery: bool) -> Option<usize> {
+    let network = network(maze, slippery);
+    let mut visited = vec![false; network.junctions.len()];
+    visited[0] = true;
+    hike(&network.trails, 0, 1, &mut visited)
+}
+
+fn network(maze: &Grid<Field>, slippery: bool) -> Network {
+    let bottom = maze.height() - 1;
+    let (start, target) = (find_path(maze, 0), find_path(maze, bottom));
+    let (start, target) = start.zip(target).expect("A start and a target");
+    let junctions: Vec<Position> = [start, target]
+        .into_iter()
+        .chain(maze.positions().filter(|p| {
+            maze[*p] != Field::Forest
+                && maze
+                    .neighbours(*p)
+                    .filter(|n| maze[*n] != Field::Forest)
+                    .count()
+                    > 2
+        }))
+        .collect();
+    let mut index = maze.map(|_| None);
+    for (i, j) in junctions.iter().enumerate() {
+        index[*j] = Some(i);
+    }
+    let trails = junctions
+        .iter()
+        .enumerate()
+        .map(|(from, &pos)| {
+            let mut trails: Vec<Trail> = Vec::new();
+            for first in moves(maze, pos, slippery) {
+                // The trail is walked up to the next junction, a dead end reaching none.
+                let reached =
+                    search::bfs_distances(first, along_trail(maze, &index, pos, slippery))
+                        .iter()
+                        .find_map(|(p, steps)| Some((index[*p]?, steps + 1)));
+                let Some((to, steps)) = reached else {
+                    continue;
+                };
+                let trail = Trail { to, steps, first };
+                // Only the longest of several trails between two junctions is of interest.
+                match trails.iter_mut().find(|t| t.to == to) {
+                    Some(t) if t.steps < trail.steps => *t = trail,
+                    Some(_) => {}
+                    None if to != from => trails.push(trail),
+                    None => {}
+                }
+            }
+            trails
+        })
+        .collect();
+    Network {
+        junctions,
+        index,
+        trails,
+    }
+}
+
+/// The tiles to walk to from a tile of a trail leaving the junction at `from`, none once the
+/// trail reaches the next junction.
+///
+/// Tiles between junctions have at most one way on besides the way back, so a search with these
+/// successors follows the trail.
+fn along_trail<'a>(
+    maze: &'a Grid<Field>,
+    index: &'a Grid<Option<usize>>,
+    from: Position,
+    slippery: bool,
+) -> impl FnMut(&Position) -> Vec<Position> + 'a {
+    move |p| match index[*p] {
+        Some(_) => Vec::new(),
+        None => moves(maze, *p, slippery)
+            .into_iter()
+            .filter(|n| *n != from)
+            .collect(),
+    }
+}
+
+/// The most steps from junction `from` to junction `to` without revisiting a junction marked as
+/// `visited`.
+fn hike(trails: &[Vec<Trail>], from: usize, to: usize, visited: &mut [bool]) -> Option<usize> {
+    if from == to {
+        return Some(0);
+    }
+    let mut longest = None;
+    for trail in &trails[from] {
+        if visited[trail.to] {
+            continue;
+        }
+        visited[trail.to] = true;
+        if let Some(steps) = hike(trails, trail.to, to, visited) {
+            longest = longest.max(Some(trail.steps + steps));
+        }
+        visited[trail.to] = false;
+    }
+    longest
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 94);
+    assert_eq!(Solution::part2(&input), 154);
+}
+
+#[test]
+fn test_parallel_trails() {
+    // Both trails lead from the junction below the start to the one above the target.
+    let maze =
+        Solution::parse_str("#.#####\n#.....#\n#.###.#\n#.###.#\n#.....#\n#.#####\n").unwrap();
+    assert_eq!(longest_hike(&maze, true), Some(13));
+    assert_eq!(longest_hike(&maze, false), Some(13));
+}
+
+#[test]
+fn test_many_junctions() {
+    // A dead end branches off the trail down every other row.
+    let rows: String = (0..100).map(|_| "#..##\n#.###\n").collect();
+    let maze = Solution::parse_str(&format!("#.###\n{rows}")).unwrap();
+    assert_eq!(longest_hike(&maze, false), Some(200));
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Solution::parse_str("#").unwrap_err();
+    assert_eq!(e, ParseError::new("#", "a path in the top row").at(1, 1));
+    let e = Solution::parse_str(".").unwrap_err();
+    assert_eq!(e, ParseError::end("a bottom row").on_line(2));
+    let e = Solution::parse_str("#.\n##").unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::new("##", "a path in the bottom row").at(2, 1)
+    );
+    let e = Solution::parse_str(".#\n#.").unwrap_err();
+    assert_eq!(
+        e,
+        ParseError::new("#.", "a path reachable down the slopes").at(2, 1)
+    );
+    let e = Solution::parse_str("#.#\n#^#\n#.#").unwrap_err();
+    assert_eq!(e.expected, "a path reachable down the slopes");
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(23, env!("CARGO_MANIFEST_DIR"));
+}
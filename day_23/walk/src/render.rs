@@ -0,0 +1,52 @@
+use crate::{along_trail, hike, network, Field};
+use aoc_core::search;
+use grid::{Canvas, Grid, Rgb};
+
+/// Draws the longest hike following the slopes, marking its steps with `O` like the puzzle.
+pub fn render(maze: &Grid<Field>) -> Canvas {
+    let mut canvas = Canvas::new(maze);
+    let network = network(maze, true);
+    let mut visited = vec![false; network.junctions.len()];
+    visited[0] = true;
+    let Some(mut left) = hike(&network.trails, 0, 1, &mut visited) else {
+        return canvas;
+    };
+    // Follow the trails that still allow a hike of the remaining steps to the target.
+    let mut from = 0;
+    while from != 1 {
+        let trail = *network.trails[from]
+            .iter()
+            .find(|t| {
+                if visited[t.to] {
+                    return false;
+                }
+                visited[t.to] = true;
+                let continues = hike(&network.trails, t.to, 1, &mut visited);
+                visited[t.to] = false;
+                continues == Some(left - t.steps)
+            })
+            .expect("A trail continuing the longest hike");
+        let successors = along_trail(maze, &network.index, network.junctions[from], true);
+        let path = search::bfs(trail.first, successors, |p| {
+            network.index[*p] == Some(trail.to)
+        })
+        .expect("The trail between the junctions");
+        canvas
+            .paint(path.iter().copied(), Rgb::RED)
+            .draw(path, b'O');
+        visited[trail.to] = true;
+        (from, left) = (trail.to, left - trail.steps);
+    }
+    canvas.draw([network.junctions[0]], b'S');
+    canvas
+}
+
+#[test]
+fn test_render() {
+    use aoc_core::Solver;
+
+    let maze = crate::Solution::parse_str(include_str!("../sample")).unwrap();
+    let text = render(&maze).text();
+    assert_eq!(text.bytes().filter(|b| *b == b'O').count(), 94);
+    assert_eq!(text.lines().next(), Some("#S#####################"));
+}
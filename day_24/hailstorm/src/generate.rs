@@ -0,0 +1,90 @@
+use aoc_core::rng::Rng;
+use std::collections::HashSet;
+
+/// Generates `size` hailstones, at least three, that a rock thrown from a hidden position hits
+/// one after the other.
+pub fn generate(size: usize, seed: u64) -> String {
+    hailstorm(size, seed).0
+}
+
+/// The hailstones and the sum of the coordinates the rock is thrown from.
+///
+/// Each hailstone is placed where it is at time zero to meet the rock at a random time, so the
+/// positions are in the range of the puzzle input around the test area.
+fn hailstorm(size: usize, seed: u64) -> (String, i128) {
+    let mut rng = Rng::new(seed);
+    let rock_velocity = [(); 3].map(|_| rng.range(0..301) as i64 - 150);
+    let rock = [(); 3].map(|_| rng.range(200_000_000_000_000..400_000_000_000_000) as i64);
+    let mut times = HashSet::new();
+    let mut out = String::new();
+    while times.len() < size.max(3) {
+        let time = rng.range(10_000_000_000..1_000_000_000_000) as i64;
+        let hail_velocity = [(); 3].map(|_| rng.range(0..601) as i64 - 300);
+        let position = [0, 1, 2].map(|i| rock[i] + time * (rock_velocity[i] - hail_velocity[i]));
+        // The two dimensional paths of part one need a slope.
+        let valid = position
+            .iter()
+            .all(|p| (100_000_000_000_000..500_000_000_000_000).contains(p))
+            && hail_velocity[0] != 0
+            && hail_velocity[1] != 0;
+        if valid && times.insert(time) {
+            let [px, py, pz] = position;
+            let [vx, vy, vz] = hail_velocity;
+            out.push_str(&format!("{px}, {py}, {pz} @ {vx}, {vy}, {vz}\n"));
+        }
+    }
+    (out, rock.iter().map(|c| *c as i128).sum())
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    /// Counts the pairs of hailstones whose paths cross inside the test area in the future,
+    /// ignoring the z axis, with exact integer arithmetic.
+    fn crossings(text: &str) -> usize {
+        let stones: Vec<Vec<i128>> = text
+            .lines()
+            .map(|l| {
+                l.split([',', '@'])
+                    .map(|n| n.trim().parse().unwrap())
+                    .collect()
+            })
+            .collect();
+        let (low, high) = (200000000000000, 400000000000000);
+        let mut count = 0;
+        for (i, a) in stones.iter().enumerate() {
+            for b in &stones[i + 1..] {
+                // a + t * va = b + s * vb with t = t_num / den and s = s_num / den.
+                let den = a[3] * b[4] - a[4] * b[3];
+                if den == 0 {
+                    continue;
+                }
+                let (dx, dy) = (b[0] - a[0], b[1] - a[1]);
+                let (t_num, s_num) = (dx * b[4] - dy * b[3], dx * a[4] - dy * a[3]);
+                let sign = den.signum();
+                let (den, t_num, s_num) = (den * sign, t_num * sign, s_num * sign);
+                let inside =
+                    |p: i128, v: i128| (low * den..=high * den).contains(&(p * den + v * t_num));
+                if t_num >= 0 && s_num >= 0 && inside(a[0], a[3]) && inside(a[1], a[4]) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    for seed in 0..5 {
+        let (text, rock) = hailstorm(100, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(Solution::part1(&input), crossings(&text));
+        assert_eq!(Solution::part2(&input), rock);
+    }
+    // Three hailstones are all the throw is solved from, however badly conditioned they are.
+    for seed in 0..500 {
+        let (text, rock) = hailstorm(3, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(Solution::part2(&input), rock, "seed {seed}");
+    }
+}
@@ -0,0 +1,298 @@
+mod generate;
+use aoc_core::{parse_token, ParseError, Solver};
+pub use generate::generate;
+use std::{io::BufRead, str::FromStr};
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct Hailstone {
+    pos: DimensionContainter,
+    vel: DimensionContainter,
+    m: f64,
+    b: f64,
+}
+
+impl Hailstone {
+    pub fn new(pos: DimensionContainter, vel: DimensionContainter) -> Self {
+        let m = vel.y / vel.x;
+        let b = pos.y - pos.x * m;
+        Self { pos, vel, m, b }
+    }
+
+    pub fn intersection(&self, other: &Hailstone) -> TwoDimIntersectionResult {
+        //  y = x * m + b
+        //  b = y - (xm)
+        // Parallel
+        if self.m == other.m {
+            return TwoDimIntersectionResult::None;
+        }
+        let x = (other.b - self.b) / (self.m - other.m);
+        let y = x * self.m + self.b;
+        if self.vel.x > 0.0 && x < self.pos.x
+            || self.vel.x < 0.0 && x > self.pos.x
+            || other.vel.x > 0.0 && x < other.pos.x
+            || other.vel.x < 0.0 && x > other.pos.x
+            || self.vel.y > 0.0 && y < self.pos.y
+            || self.vel.y < 0.0 && y > self.pos.y
+            || other.vel.y > 0.0 && y < other.pos.y
+            || other.vel.y < 0.0 && y > other.pos.y
+        {
+            return TwoDimIntersectionResult::Past { x, y };
+        }
+        TwoDimIntersectionResult::Intersection { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum TwoDimIntersectionResult {
+    Intersection { x: f64, y: f64 },
+    Past { x: f64, y: f64 },
+    None,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct DimensionContainter {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl DimensionContainter {
+    fn as_int(&self) -> [i128; 3] {
+        [self.x as i128, self.y as i128, self.z as i128]
+    }
+}
+
+impl FromStr for DimensionContainter {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let values = s
+            .split(',')
+            .map(|v| parse_token(s, v.trim(), "a number"))
+            .collect::<Result<Vec<f64>, _>>()?;
+        let [x, y, z] = values[..] else {
+            return Err(ParseError::in_text(s, s.trim(), "three values"));
+        };
+        Ok(Self { x, y, z })
+    }
+}
+
+fn parse_hailstones(input: impl BufRead) -> Result<Vec<Hailstone>, ParseError> {
+    let hailstones: Vec<Hailstone> = aoc_core::lines(input)
+        .map(|line| {
+            let (n, line) = line?;
+            let Some((pos, vel)) = line.split_once('@') else {
+                let expected = "a position and a velocity seperated by `@`";
+                return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+            };
+            let parse = |part: &str| {
+                part.parse::<DimensionContainter>()
+                    .map_err(|e| e.within(&line, part).on_line(n))
+            };
+            let (pos, vel) = (parse(pos)?, parse(vel)?);
+            Ok(Hailstone::new(pos, vel))
+        })
+        .collect::<Result<_, _>>()?;
+    // The throw is solved from the first three hailstones.
+    if hailstones.len() < 3 {
+        return Err(ParseError::end("three hailstones").on_line(hailstones.len() + 1));
+    }
+    Ok(hailstones)
+}
+
+/// Counts the future crossings of hailstone paths inside the test area spanning `min..=max` on
+/// both the x and y axis.
+pub fn count_intersections(hailstones: &[Hailstone], min: f64, max: f64) -> usize {
+    let mut count = 0;
+    for i in 0..hailstones.len() {
+        let s = hailstones[i];
+        for o in hailstones.iter().skip(i + 1) {
+            if let TwoDimIntersectionResult::Intersection { x, y } = s.intersection(o) {
+                if (min..=max).contains(&x) && (min..=max).contains(&y) {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Finds the position and velocity a rock has to be thrown with to hit every hailstone.
+///
+/// For the rock at `p` moving with `v` and every hailstone `p_i`, `v_i` the vectors
+/// `p - p_i` and `v - v_i` are parallel, so `(p - p_i) x (v - v_i) = 0`. Subtracting
+/// this equation for two hailstones cancels the only non linear term `p x v` and leaves
+/// three linear equations. Two pairs of hailstones are enough to estimate the velocity.
+///
+/// The coefficients reach 10^17, so the estimate solved with `f64` is only close to the integer
+/// velocity. The integer velocities around it are tried nearest first, each giving an exact
+/// position with integer arithmetic that has to hit every hailstone, so a rounding error can
+/// only make the throw take longer to find and never yields a wrong one.
+///
+/// Returns `None` for fewer than three hailstones or if no throw hits all of them.
+pub fn throw_rock(hailstones: &[Hailstone]) -> Option<([i128; 3], [i128; 3])> {
+    let stones: Vec<([i128; 3], [i128; 3])> = hailstones
+        .iter()
+        .map(|h| (h.pos.as_int(), h.vel.as_int()))
+        .collect();
+    let [(p_0, v_0), (p_1, v_1), (p_2, v_2), ..] = stones[..] else {
+        return None;
+    };
+    let mut system = [[0.0; 7]; 6];
+    for (rows, (p_j, v_j)) in system.chunks_exact_mut(3).zip([(p_1, v_1), (p_2, v_2)]) {
+        let a = sub(v_j, v_0).map(|e| e as f64);
+        let b = sub(p_j, p_0).map(|e| e as f64);
+        let c = sub(cross(p_j, v_j), cross(p_0, v_0)).map(|e| e as f64);
+        rows[0] = [0.0, a[2], -a[1], 0.0, -b[2], b[1], c[0]];
+        rows[1] = [-a[2], 0.0, a[0], b[2], 0.0, -b[0], c[1]];
+        rows[2] = [a[1], -a[0], 0.0, -b[1], b[0], 0.0, c[2]];
+    }
+    let solution = gauss(system)?;
+    let estimate = [solution[3], solution[4], solution[5]];
+    let mut candidates: Vec<[i128; 3]> = (0..ROUNDING.pow(3))
+        .map(|i| {
+            let offset = [
+                i % ROUNDING,
+                i / ROUNDING % ROUNDING,
+                i / ROUNDING / ROUNDING,
+            ];
+            [0, 1, 2].map(|a| estimate[a].round() as i128 + offset[a] as i128 - 2)
+        })
+        .collect();
+    let distance =
+        |vel: &[i128; 3]| -> f64 { (0..3).map(|a| (vel[a] as f64 - estimate[a]).powi(2)).sum() };
+    candidates.sort_by(|a, b| distance(a).total_cmp(&distance(b)));
+    candidates
+        .into_iter()
+        .find_map(|vel| throw_with(&stones, vel).map(|pos| (pos, vel)))
+}
+
+/// The number of integers tried around every component of the estimated velocity.
+const ROUNDING: usize = 5;
+
+/// The position a rock thrown with `vel` has to start from to hit every hailstone, if any.
+fn throw_with(stones: &[([i128; 3], [i128; 3])], vel: [i128; 3]) -> Option<[i128; 3]> {
+    let [_, (p_1, v_1), (p_2, v_2), ..] = stones[..] else {
+        return None;
+    };
+    // The rock hits p_1 + t * (v_1 - vel) and p_2 + s * (v_2 - vel) in the same spot.
+    let (w_1, w_2) = (sub(v_1, vel), sub(v_2, vel));
+    let numerator = cross(sub(p_2, p_1), w_2);
+    let denominator = cross(w_1, w_2);
+    let axis = (0..3).find(|i| denominator[*i] != 0)?;
+    if numerator[axis] % denominator[axis] != 0 {
+        return None;
+    }
+    let t = numerator[axis] / denominator[axis];
+    let pos = [0, 1, 2].map(|i| p_1[i] + t * w_1[i]);
+    stones
+        .iter()
+        .all(|(p, v)| cross(sub(pos, *p), sub(vel, *v)) == [0; 3])
+        .then_some(pos)
+}
+
+/// Solves the linear system given as augmented matrix with partial pivoting.
+fn gauss<const N: usize, const M: usize>(mut m: [[f64; M]; N]) -> Option<[f64; N]> {
+    for col in 0..N {
+        let pivot = (col..N).max_by(|a, b| m[*a][col].abs().total_cmp(&m[*b][col].abs()))?;
+        if m[pivot][col] == 0.0 {
+            return None;
+        }
+        m.swap(col, pivot);
+        for row in 0..N {
+            if row == col {
+                continue;
+            }
+            let pivot = m[col];
+            let factor = m[row][col] / pivot[col];
+            for (e, p) in m[row][col..].iter_mut().zip(&pivot[col..]) {
+                *e -= factor * p;
+            }
+        }
+    }
+    let mut solution = [0.0; N];
+    for (i, s) in solution.iter_mut().enumerate() {
+        *s = m[i][M - 1] / m[i][i];
+    }
+    Some(solution)
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Vec<Hailstone>;
+    type Part1 = usize;
+    type Part2 = i128;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_hailstones(input)
+    }
+
+    /// Counts the future crossings of hailstone paths inside the test area.
+    fn part1(hailstones: &Self::Input) -> usize {
+        count_intersections(hailstones, 200000000000000.0, 400000000000000.0)
+    }
+
+    /// Sums the coordinates of the position the rock has to be thrown from.
+    ///
+    /// # Panics
+    /// If no throw hits every hailstone.
+    fn part2(hailstones: &Self::Input) -> i128 {
+        let (pos, _) = throw_rock(hailstones).expect("A throw hitting all hailstones");
+        pos.iter().sum()
+    }
+}
+
+#[test]
+fn test_sample() {
+    let hailstones = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(count_intersections(&hailstones, 7.0, 27.0), 2);
+    assert_eq!(Solution::part2(&hailstones), 47);
+}
+
+#[test]
+fn test_throw_rock() {
+    let hailstones = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(throw_rock(&hailstones), Some(([24, 13, 10], [-3, 1, 2])));
+    let stones: Vec<_> = hailstones
+        .iter()
+        .map(|h| (h.pos.as_int(), h.vel.as_int()))
+        .collect();
+    // Velocities next to the right one do not hit every hailstone.
+    assert_eq!(throw_with(&stones, [-3, 1, 2]), Some([24, 13, 10]));
+    assert_eq!(throw_with(&stones, [-2, 1, 2]), None);
+    assert_eq!(throw_with(&stones, [-3, 1, 3]), None);
+    assert_eq!(throw_rock(&hailstones[..2]), None);
+    assert_eq!(throw_rock(&[]), None);
+    // A hailstone off the path of the throw through the others.
+    let mut missed = hailstones.clone();
+    missed[3] = Hailstone::new(missed[3].pos, missed[4].vel);
+    assert_eq!(throw_rock(&missed), None);
+}
+
+#[test]
+fn test_parse_error() {
+    let e = Solution::parse_str("").unwrap_err();
+    assert_eq!(e, ParseError::end("three hailstones").on_line(1));
+    let e = Solution::parse_str("19, 13, 30 @ -2, 1, -2\n").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (2, "three hailstones"));
+    let e = Solution::parse_str("19, 13 @ -2, 1, -2").unwrap_err();
+    assert_eq!((e.line, e.expected.as_str()), (1, "three values"));
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(24, env!("CARGO_MANIFEST_DIR"));
+}
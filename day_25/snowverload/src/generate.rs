@@ -0,0 +1,122 @@
+use aoc_core::rng::Rng;
+use std::collections::{BTreeMap, HashSet};
+
+/// Generates `size` components, at least ten, wired into two groups joined by three wires.
+pub fn generate(size: usize, seed: u64) -> String {
+    network(size, seed).0
+}
+
+/// The wiring diagram and the product of the sizes of the two groups.
+///
+/// Every component is wired to the two before and the two after it in a random ring of its
+/// group, and some more wires are added at random. Cutting a group takes at least four wires
+/// then, so the three wires between the groups are the only ones to cut.
+fn network(size: usize, seed: u64) -> (String, usize) {
+    let mut rng = Rng::new(seed);
+    let size = size.max(10);
+    let first = rng.range(5..size as u64 - 4) as usize;
+    let names = names(&mut rng, size);
+    let (a, b) = names.split_at(first);
+    let mut wires = HashSet::new();
+    for group in [a, b] {
+        let mut ring: Vec<&String> = group.iter().collect();
+        rng.shuffle(&mut ring);
+        for (i, name) in ring.iter().enumerate() {
+            for step in [1, 2] {
+                wires.insert(wire(name, ring[(i + step) % ring.len()]));
+            }
+        }
+        for _ in 0..group.len() {
+            let (x, y) = (rng.choose(group).as_str(), rng.choose(group));
+            if x != y {
+                wires.insert(wire(x, y));
+            }
+        }
+    }
+    for _ in 0..3 {
+        // Distinct wires, as a ring holds at least five components.
+        loop {
+            if wires.insert(wire(rng.choose(a).as_str(), rng.choose(b).as_str())) {
+                break;
+            }
+        }
+    }
+    // List every wire once, with either of its ends.
+    let mut wires: Vec<(&str, &str)> = wires.into_iter().collect();
+    wires.sort_unstable();
+    let mut lines: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (x, y) in wires {
+        let (from, to) = if rng.chance(1, 2) { (x, y) } else { (y, x) };
+        lines.entry(from).or_default().push(to);
+    }
+    let mut lines: Vec<String> = lines
+        .into_iter()
+        .map(|(from, to)| format!("{from}: {}\n", to.join(" ")))
+        .collect();
+    rng.shuffle(&mut lines);
+    (lines.concat(), a.len() * b.len())
+}
+
+/// A wire between two components, given by their names in order.
+fn wire<'a>(x: &'a str, y: &'a str) -> (&'a str, &'a str) {
+    (x.min(y), x.max(y))
+}
+
+/// `len` distinct names of at least three lowercase letters, longer ones once three run out.
+fn names(rng: &mut Rng, len: usize) -> Vec<String> {
+    let mut letters = 3;
+    while 26usize.pow(letters) < len {
+        letters += 1;
+    }
+    let count = 26u64.pow(letters);
+    // A stride coprime to the number of names steps through all of them before coming back.
+    let stride = loop {
+        let stride = rng.range(1..count);
+        if !stride.is_multiple_of(2) && !stride.is_multiple_of(13) {
+            break stride;
+        }
+    };
+    let start = rng.range(0..count);
+    let mut names: Vec<String> = (0..len as u64)
+        .map(|i| name((start + i * stride) % count, letters))
+        .collect();
+    rng.shuffle(&mut names);
+    names
+}
+
+/// The name of `letters` lowercase letters spelling `index` in base 26.
+fn name(mut index: u64, letters: u32) -> String {
+    (0..letters)
+        .map(|_| {
+            let letter = (b'a' + (index % 26) as u8) as char;
+            index /= 26;
+            letter
+        })
+        .collect()
+}
+
+#[test]
+fn test_generate() {
+    use crate::Solution;
+    use aoc_core::Solver;
+
+    for seed in 0..5 {
+        let (text, groups) = network(30, seed);
+        let input = Solution::parse_str(&text).unwrap();
+        assert_eq!(Solution::part1(&input), groups);
+        // Every component is wired to at least four others, so no other cut of three wires exists.
+        let mut wires: BTreeMap<&str, usize> = BTreeMap::new();
+        for line in text.lines() {
+            let (from, to) = line.split_once(": ").unwrap();
+            for to in to.split(' ') {
+                *wires.entry(from).or_default() += 1;
+                *wires.entry(to).or_default() += 1;
+            }
+        }
+        assert_eq!(wires.len(), 30);
+        assert!(wires.values().all(|w| *w >= 4));
+    }
+    let names = names(&mut Rng::new(1), 20000);
+    assert_eq!(names.iter().collect::<HashSet<_>>().len(), 20000);
+    assert!(names.iter().all(|name| name.len() == 4));
+}
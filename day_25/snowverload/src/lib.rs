@@ -0,0 +1,115 @@
+mod generate;
+use aoc_core::{interner::Interner, search, ParseError, Solver};
+pub use generate::generate;
+
+use std::{collections::HashSet, io::BufRead};
+
+/// The components, by their ids, and the components each of them is wired to.
+#[derive(Debug, Clone)]
+pub struct Graph {
+    wires: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// The components on the side of `source` of a cut of at most `most` wires separating it from
+    /// `sink`, `None` if more wires are needed.
+    ///
+    /// Every wire carries one unit of flow in either direction, so the number of paths found from
+    /// `source` to `sink` that share no wire is the number of wires separating them.
+    fn cut(&self, source: usize, sink: usize, most: usize) -> Option<HashSet<usize>> {
+        // The directions the wires carry flow in.
+        let mut flow: HashSet<(usize, usize)> = HashSet::new();
+        for _ in 0..=most {
+            let residual = |&c: &usize| {
+                let flow = &flow;
+                self.wires[c]
+                    .iter()
+                    .copied()
+                    .filter(move |&next| !flow.contains(&(c, next)))
+            };
+            let Some(path) = search::bfs(source, residual, |&c| c == sink) else {
+                let side = search::bfs_distances(source, residual);
+                return Some(side.iter().map(|(c, _)| *c).collect());
+            };
+            for step in path.windows(2) {
+                if !flow.remove(&(step[1], step[0])) {
+                    flow.insert((step[0], step[1]));
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_graph(input: impl BufRead) -> Result<Graph, ParseError> {
+    let mut names = Interner::new();
+    let mut wires: Vec<Vec<usize>> = Vec::new();
+    for line in aoc_core::lines(input) {
+        let (n, line) = line?;
+        let Some((src, dst)) = line.split_once(':') else {
+            let expected = "a component and its connections seperated by `:`";
+            return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+        };
+        let src = names.intern(src) as usize;
+        for dst in dst.split_whitespace() {
+            let dst = names.intern(dst) as usize;
+            wires.resize(names.len(), Vec::new());
+            wires[src].push(dst);
+            wires[dst].push(src);
+        }
+    }
+    if wires.is_empty() {
+        return Err(ParseError::end("a component"));
+    }
+    Ok(Graph { wires })
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    type Input = Graph;
+    type Part1 = usize;
+    /// There is no second puzzle on the last day.
+    type Part2 = &'static str;
+
+    fn parse(input: impl BufRead) -> Result<Self::Input, ParseError> {
+        parse_graph(input)
+    }
+
+    /// Multiplies the sizes of the two groups that remain after cutting three wires.
+    ///
+    /// The components farthest apart are most likely in different groups, so the cut is looked
+    /// for between one of them and every other component, farthest first.
+    ///
+    /// # Panics
+    /// If no three wires split the components into two groups.
+    fn part1(graph: &Self::Input) -> usize {
+        let farthest = |from: usize| -> Vec<usize> {
+            let distances = search::bfs_distances(from, |c| graph.wires[*c].iter().copied());
+            let mut found: Vec<(usize, usize)> = distances.iter().map(|(c, d)| (d, *c)).collect();
+            found.sort_unstable_by(|a, b| b.cmp(a));
+            found.into_iter().map(|(_, c)| c).collect()
+        };
+        let source = farthest(0)[0];
+        let side = farthest(source)
+            .into_iter()
+            .find_map(|sink| graph.cut(source, sink, 3))
+            .expect("Three wires split the components into two groups");
+        side.len() * (graph.wires.len() - side.len())
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        "Merry Christmas"
+    }
+}
+
+#[test]
+fn test_sample() {
+    let input = Solution::parse_str(include_str!("../sample")).unwrap();
+    assert_eq!(Solution::part1(&input), 54);
+}
+
+#[test]
+fn test_answers() {
+    aoc_core::check_answers::<Solution>(25, env!("CARGO_MANIFEST_DIR"));
+}
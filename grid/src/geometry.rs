@@ -0,0 +1,130 @@
+use crate::Tile;
+
+/// One of the four directions on a grid, with rows growing downwards.
+///
+/// As a tile it is the arrow pointing in the direction, like the slopes of the hiking trails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Tile)]
+pub enum Direction {
+    #[tile(b'^')]
+    Up,
+    #[tile(b'>')]
+    Right,
+    #[tile(b'v')]
+    Down,
+    #[tile(b'<')]
+    Left,
+}
+
+impl Direction {
+    /// All directions in clockwise order starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The direction after turning 90 degrees counter clockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    /// The direction after turning 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        self.turn_left().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// Whether the direction moves between rows.
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::Up | Direction::Down)
+    }
+
+    /// The change of `(row, column)` when taking a single step.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        }
+    }
+}
+
+/// A position on a grid given by its row and column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+
+    /// The position one step in `dir`, `None` if it would leave the first row or column.
+    pub fn step(self, dir: Direction) -> Option<Self> {
+        self.step_by(dir, 1)
+    }
+
+    /// The position `n` steps in `dir`, `None` if it would leave the first row or column.
+    pub fn step_by(self, dir: Direction, n: usize) -> Option<Self> {
+        let n = isize::try_from(n).ok()?;
+        let (dr, dc) = dir.offset();
+        Some(Self {
+            row: self.row.checked_add_signed(dr.checked_mul(n)?)?,
+            col: self.col.checked_add_signed(dc.checked_mul(n)?)?,
+        })
+    }
+
+    /// The Manhattan distance to `other`.
+    pub fn distance(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+impl From<(usize, usize)> for Position {
+    fn from((row, col): (usize, usize)) -> Self {
+        Self { row, col }
+    }
+}
+
+impl From<Position> for (usize, usize) {
+    fn from(p: Position) -> Self {
+        (p.row, p.col)
+    }
+}
+
+#[test]
+fn test_turns() {
+    for d in Direction::ALL {
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.turn_right().turn_right(), d.reverse());
+        assert_eq!(d.is_vertical(), d.offset().1 == 0);
+    }
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+}
+
+#[test]
+fn test_step() {
+    let p = Position::new(1, 2);
+    assert_eq!(p.step(Direction::Up), Some(Position::new(0, 2)));
+    assert_eq!(p.step_by(Direction::Up, 2), None);
+    assert_eq!(p.step_by(Direction::Left, 2), Some(Position::new(1, 0)));
+    assert_eq!(p.step(Direction::Down).map(Into::into), Some((2, 2)));
+    assert_eq!(p.distance(Position::new(4, 0)), 5);
+}
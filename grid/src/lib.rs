@@ -0,0 +1,291 @@
+// Lets the code derived for the tiles of this crate refer to it by name.
+extern crate self as grid;
+
+mod geometry;
+mod render;
+mod tile;
+
+pub use geometry::{Direction, Position};
+pub use render::{Canvas, Rgb};
+pub use tile::Tile;
+pub use tile_derive::Tile;
+
+use aoc_core::ParseError;
+use std::{
+    fmt::{self, Display},
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular two dimensional map stored row by row in a single vector.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid from its cells given row by row.
+    ///
+    /// # Panics
+    /// If the number of cells does not match the dimensions.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "Cells to fill the grid");
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a grid where every cell holds `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Creates a grid from its rows.
+    ///
+    /// # Panics
+    /// If the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|r| r.len() == width),
+            "Rows of equal length"
+        );
+        Self::new(width, height, rows.into_iter().flatten().collect())
+    }
+
+    /// Parses a grid with one row per line, mapping every byte to a tile.
+    ///
+    /// Errors returned by `tile` are placed at the line and column of the byte. Empty input and
+    /// lines of different lengths are reported as errors as well.
+    pub fn parse(
+        input: impl BufRead,
+        mut tile: impl FnMut(u8) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in aoc_core::lines(input) {
+            let (n, line) = line?;
+            if *width.get_or_insert(line.len()) != line.len() {
+                let expected = format!("a row of {} tiles", width.unwrap_or_default());
+                return Err(ParseError::in_text(&line, &line, expected).on_line(n));
+            }
+            for (j, b) in line.bytes().enumerate() {
+                cells.push(tile(b).map_err(|e| e.at(n, j + 1))?);
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Self::new(width, height, cells)),
+            _ => Err(ParseError::end("a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    /// The position one step from `pos` in `dir` if it lies inside the grid.
+    pub fn step(&self, pos: Position, dir: Direction) -> Option<Position> {
+        pos.step(dir).filter(|p| self.contains(*p))
+    }
+
+    /// The positions above, right, below and left of `pos` that lie inside the grid.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// The positions surrounding `pos` including the diagonals that lie inside the grid.
+    pub fn neighbours_diagonal(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        (-1_isize..=1)
+            .flat_map(|dr| (-1_isize..=1).map(move |dc| (dr, dc)))
+            .filter(|d| *d != (0, 0))
+            .filter_map(move |(dr, dc)| {
+                Some(Position::new(
+                    pos.row.checked_add_signed(dr)?,
+                    pos.col.checked_add_signed(dc)?,
+                ))
+            })
+            .filter(|p| self.contains(*p))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        &mut self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// Iterates over the rows, none if the grid is empty.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // A grid without columns has no cells, and chunks may not be empty.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|c| self.column(c))
+    }
+
+    /// Iterates over all positions row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| Position::new(r, c)))
+    }
+
+    /// Iterates over all cells row by row together with their position.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell matching the predicate.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|i| Position::new(i / self.width, i % self.width))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Mirrors the grid along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(|c| c.cloned()).collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self
+            .columns()
+            .flat_map(|c| c.cloned().collect::<Vec<_>>().into_iter().rev())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+
+    /// Rotates the grid by 90 degrees counter clockwise.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|c| self.column(c).cloned())
+            .collect();
+        Grid::new(self.height, self.width, cells)
+    }
+}
+
+/// Writes the bytes of the tiles like in the puzzle input, one line per row.
+impl<T: Tile> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            let bytes: Vec<u8> = row.iter().map(|t| t.to_byte()).collect();
+            writeln!(f, "{}", String::from_utf8_lossy(&bytes))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, Position { row, col }: Position) -> &Self::Output {
+        assert!(col < self.width, "Column {col} out of bounds");
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, Position { row, col }: Position) -> &mut Self::Output {
+        assert!(col < self.width, "Column {col} out of bounds");
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+#[test]
+fn test_parse() {
+    let grid = Grid::parse(&b"#.\n.#\n#."[..], |b| Ok(b == b'#')).unwrap();
+    assert_eq!((grid.width(), grid.height()), (2, 3));
+    assert!(grid[Position::new(1, 1)] && !grid[Position::new(2, 1)]);
+    assert_eq!(grid.get(Position::new(3, 0)), None);
+    let e = Grid::parse(&b"#.\n.#.\n"[..], |b| Ok(b == b'#')).unwrap_err();
+    assert_eq!((e.line, e.token.as_str()), (2, ".#."));
+    let e = Grid::parse(&b"#.\n.x\n"[..], |b| match b {
+        b'#' | b'.' => Ok(b),
+        _ => Err(ParseError::new(b as char, "a tile")),
+    })
+    .unwrap_err();
+    assert_eq!((e.line, e.column), (2, 2));
+    let grid = Grid::parse(&b"#.\n.#\n"[..], Ok).unwrap();
+    assert_eq!(grid.to_string(), "#.\n.#\n");
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::filled(3, 2, 0);
+    let mut n: Vec<Position> = grid.neighbours(Position::new(0, 0)).collect();
+    n.sort();
+    assert_eq!(n, [Position::new(0, 1), Position::new(1, 0)]);
+    assert_eq!(grid.neighbours(Position::new(1, 1)).count(), 3);
+    assert_eq!(grid.neighbours_diagonal(Position::new(0, 1)).count(), 5);
+    let p = Position::new(1, 1);
+    assert_eq!(grid.step(p, Direction::Right), Some(Position::new(1, 2)));
+    assert_eq!(grid.step(Position::new(1, 2), Direction::Right), None);
+    assert_eq!(grid.step(Position::new(0, 2), Direction::Up), None);
+}
+
+#[test]
+fn test_transform() {
+    let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(grid.column(1).collect::<Vec<_>>(), [&2, &5]);
+    let t = grid.transpose();
+    assert_eq!(t.rows().collect::<Vec<_>>(), [[1, 4], [2, 5], [3, 6]]);
+    assert_eq!(Grid::filled(0, 0, 0).rows().count(), 0);
+    let r = grid.rotate_clockwise();
+    assert_eq!(r.rows().collect::<Vec<_>>(), [[4, 1], [5, 2], [6, 3]]);
+    let l = grid.rotate_counter_clockwise();
+    assert_eq!(l.rows().collect::<Vec<_>>(), [[3, 6], [2, 5], [1, 4]]);
+    assert_eq!(r.rotate_counter_clockwise(), grid);
+}
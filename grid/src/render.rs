@@ -0,0 +1,292 @@
+//! Drawings of grids for debugging, with cells highlighted by overlays.
+//!
+//! A [`Canvas`] starts out as the bytes of the tiles of a grid. Overlays color cells and may
+//! replace their glyph, like arrows along a path. The result is written as plain text, as text
+//! colored with ANSI escape codes for terminals or as a PPM or PNG image.
+use crate::{Direction, Grid, Position, Tile};
+use std::fmt::Write;
+
+/// A color given by its red, green and blue channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const RED: Rgb = Rgb(230, 60, 50);
+    pub const GREEN: Rgb = Rgb(80, 200, 90);
+    pub const BLUE: Rgb = Rgb(70, 120, 230);
+    pub const YELLOW: Rgb = Rgb(240, 210, 60);
+    pub const ORANGE: Rgb = Rgb(240, 140, 40);
+    pub const GRAY: Rgb = Rgb(110, 110, 110);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    glyph: u8,
+    color: Option<Rgb>,
+}
+
+/// A grid of glyphs, each with an optional color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    /// A canvas showing the bytes of the tiles like in the puzzle input.
+    pub fn new<T: Tile>(grid: &Grid<T>) -> Self {
+        Self {
+            cells: grid.map(|t| Cell {
+                glyph: t.to_byte(),
+                color: None,
+            }),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height()
+    }
+
+    /// The glyph and color of the cell at `pos`.
+    pub fn get(&self, pos: Position) -> Option<(u8, Option<Rgb>)> {
+        self.cells.get(pos).map(|c| (c.glyph, c.color))
+    }
+
+    /// Colors the cells at the given positions.
+    pub fn paint(
+        &mut self,
+        positions: impl IntoIterator<Item = Position>,
+        color: Rgb,
+    ) -> &mut Self {
+        for p in positions {
+            self.cells[p].color = Some(color);
+        }
+        self
+    }
+
+    /// Replaces the glyph of the cells at the given positions.
+    pub fn draw(&mut self, positions: impl IntoIterator<Item = Position>, glyph: u8) -> &mut Self {
+        for p in positions {
+            self.cells[p].glyph = glyph;
+        }
+        self
+    }
+
+    /// Colors the cells of a path and draws the arrow of the direction it leaves each of them
+    /// in, the last cell keeping its glyph.
+    ///
+    /// # Panics
+    /// If two consecutive positions of the path are not adjacent.
+    pub fn path(&mut self, path: &[Position], color: Rgb) -> &mut Self {
+        for step in path.windows(2) {
+            let dir = Direction::ALL
+                .into_iter()
+                .find(|d| self.cells.step(step[0], *d) == Some(step[1]))
+                .expect("Adjacent positions along the path");
+            self.cells[step[0]].glyph = dir.to_byte();
+        }
+        self.paint(path.iter().copied(), color)
+    }
+
+    /// The glyphs without any color, one line per row.
+    pub fn text(&self) -> String {
+        let mut text = String::with_capacity((self.width() + 1) * self.height());
+        for row in self.cells.rows() {
+            text.extend(row.iter().map(|c| c.glyph as char));
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The glyphs with their colors set by 24 bit ANSI escape codes, one line per row.
+    pub fn ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if cell.color != current {
+                    match cell.color {
+                        Some(Rgb(r, g, b)) => write!(text, "\x1b[1;38;2;{r};{g};{b}m").unwrap(),
+                        None => text.push_str("\x1b[0m"),
+                    }
+                    current = cell.color;
+                }
+                text.push(cell.glyph as char);
+            }
+            if current.is_some() {
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// The color of every pixel row by row, each cell being a square of `scale` pixels.
+    ///
+    /// Cells without a color are shaded by their glyph, darkest for empty ground.
+    fn pixels(&self, scale: usize) -> Vec<Rgb> {
+        let mut pixels = Vec::with_capacity(self.width() * self.height() * scale * scale);
+        for row in self.cells.rows() {
+            let colors: Vec<Rgb> = row
+                .iter()
+                .map(|c| c.color.unwrap_or_else(|| shade(c.glyph)))
+                .collect();
+            for _ in 0..scale {
+                for color in &colors {
+                    pixels.extend(std::iter::repeat_n(*color, scale));
+                }
+            }
+        }
+        pixels
+    }
+
+    /// A binary PPM image with `scale` pixels per cell.
+    pub fn ppm(&self, scale: usize) -> Vec<u8> {
+        let header = format!(
+            "P6\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        );
+        let mut image = header.into_bytes();
+        for Rgb(r, g, b) in self.pixels(scale) {
+            image.extend([r, g, b]);
+        }
+        image
+    }
+
+    /// An uncompressed PNG image with `scale` pixels per cell.
+    pub fn png(&self, scale: usize) -> Vec<u8> {
+        let (width, height) = (self.width() * scale, self.height() * scale);
+        let pixels = self.pixels(scale);
+        // Every line of pixels starts with the type of its filter, none.
+        let mut raw = Vec::with_capacity(height * (width * 3 + 1));
+        for line in pixels.chunks(width.max(1)) {
+            raw.push(0);
+            for Rgb(r, g, b) in line {
+                raw.extend([*r, *g, *b]);
+            }
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // 8 bits per channel of RGB pixels, the only compression and filters, no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut image = b"\x89PNG\r\n\x1a\n".to_vec();
+        chunk(&mut image, b"IHDR", &header);
+        chunk(&mut image, b"IDAT", &zlib_stored(&raw));
+        chunk(&mut image, b"IEND", &[]);
+        image
+    }
+}
+
+/// The color of a cell without an overlay.
+fn shade(glyph: u8) -> Rgb {
+    match glyph {
+        b'.' | b' ' => Rgb(20, 20, 20),
+        b'#' => Rgb(120, 120, 120),
+        b'0'..=b'9' => {
+            let v = 40 + (glyph - b'0') * 22;
+            Rgb(v, v, v)
+        }
+        _ => Rgb(190, 190, 190),
+    }
+}
+
+/// Appends a PNG chunk with its length and checksum.
+fn chunk(image: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    image.extend((data.len() as u32).to_be_bytes());
+    let start = image.len();
+    image.extend(kind);
+    image.extend(data);
+    let crc = crc32(&image[start..]);
+    image.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream holding the data in stored deflate blocks without compression.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window and no preset dictionary, the check bits making it divisible by 31
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let len = block.len() as u16;
+        stream.push(blocks.peek().is_none() as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0_u32;
+    for b in data {
+        crc ^= *b as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+#[test]
+fn test_canvas() {
+    let grid = Grid::parse(&b"...\n.#.\n"[..], Ok).unwrap();
+    let mut canvas = Canvas::new(&grid);
+    let path = [(0, 0), (0, 1), (0, 2), (1, 2)].map(Position::from);
+    canvas.path(&path, Rgb::RED);
+    canvas
+        .paint([Position::new(1, 1)], Rgb::BLUE)
+        .draw([Position::new(1, 0)], b'O');
+    assert_eq!(canvas.text(), ">>v\nO#.\n");
+    assert_eq!(
+        canvas.get(Position::new(1, 1)),
+        Some((b'#', Some(Rgb::BLUE)))
+    );
+    assert_eq!(
+        canvas.ansi(),
+        "\x1b[1;38;2;230;60;50m>>v\x1b[0m\nO\x1b[1;38;2;70;120;230m#\x1b[1;38;2;230;60;50m.\x1b[0m\n"
+    );
+    let ppm = canvas.ppm(2);
+    assert!(ppm.starts_with(b"P6\n6 4\n255\n"));
+    assert_eq!(ppm.len(), 11 + 6 * 4 * 3);
+    assert_eq!(ppm[11..14], [230, 60, 50]);
+}
+
+#[test]
+fn test_png() {
+    assert_eq!(crc32(b"IEND"), 0xae42_6082);
+    assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    let grid = Grid::parse(&b"#.\n"[..], Ok).unwrap();
+    let png = Canvas::new(&grid).png(1);
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x02\0\0\0\x01"));
+    assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+    // A single stored block holding the filter byte and both pixels
+    let idat = png.windows(4).position(|w| w == b"IDAT").unwrap();
+    assert_eq!(png[idat - 4..idat], [0, 0, 0, 2 + 5 + 7 + 4]);
+    assert_eq!(png[idat + 4..idat + 11], [0x78, 0x01, 1, 7, 0, 0xf8, 0xff]);
+    assert_eq!(png[idat + 11..idat + 18], [0, 120, 120, 120, 20, 20, 20]);
+    // Blocks are split at their maximum size
+    assert_eq!(zlib_stored(&[0; 70000]).len(), 2 + 2 * 5 + 70000 + 4);
+}
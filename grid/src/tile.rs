@@ -0,0 +1,97 @@
+use aoc_core::ParseError;
+
+/// A tile of a map that is given by a single byte of the puzzle input.
+///
+/// It is usually derived with `#[derive(Tile)]`, naming the byte of every variant.
+pub trait Tile: Copy {
+    /// The tile given by `byte`, `None` if there is none.
+    fn from_byte(byte: u8) -> Option<Self>;
+
+    /// The byte giving the tile in the puzzle input.
+    fn to_byte(self) -> u8;
+
+    /// Every tile, in the order their variants are declared in.
+    fn all() -> Vec<Self>;
+
+    /// A description of the tiles for parse errors, listing their bytes like "`.` or `#`".
+    fn expected() -> String {
+        let bytes: Vec<String> = Self::all()
+            .into_iter()
+            .map(|t| format!("`{}`", t.to_byte() as char))
+            .collect();
+        match bytes.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("{} or {last}", rest.join(", ")),
+            None => "no tile at all".into(),
+        }
+    }
+
+    /// Parses the tile given by `byte`, as needed by [`Grid::parse`](crate::Grid::parse).
+    fn parse(byte: u8) -> Result<Self, ParseError> {
+        Self::from_byte(byte).ok_or_else(|| ParseError::new(byte as char, Self::expected()))
+    }
+}
+
+/// Every byte is a tile of its own, for maps kept as they are given.
+impl Tile for u8 {
+    fn from_byte(byte: u8) -> Option<Self> {
+        Some(byte)
+    }
+
+    fn to_byte(self) -> u8 {
+        self
+    }
+
+    fn all() -> Vec<Self> {
+        (0..=u8::MAX).collect()
+    }
+
+    fn expected() -> String {
+        "a byte".into()
+    }
+}
+
+#[test]
+fn test_tile() {
+    use crate::Direction;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, tile_derive::Tile)]
+    enum Field {
+        #[tile(b'.')]
+        Path,
+        #[tile(b'#')]
+        Forest,
+        Slope(Direction),
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, tile_derive::Tile)]
+    #[tile(expected = "a mirror")]
+    enum Mirror {
+        #[tile(b'/')]
+        Slash,
+        #[tile(b'\\')]
+        Backslash,
+    }
+
+    assert_eq!(Field::from_byte(b'#'), Some(Field::Forest));
+    assert_eq!(Field::from_byte(b'v'), Some(Field::Slope(Direction::Down)));
+    assert_eq!(Field::from_byte(b'x'), None);
+    assert_eq!(Field::Slope(Direction::Left).to_byte(), b'<');
+    let all = Field::all();
+    assert_eq!(all.len(), 6);
+    assert_eq!(
+        all[..3],
+        [Field::Path, Field::Forest, Field::Slope(Direction::Up)]
+    );
+    assert!(all
+        .iter()
+        .all(|t| Field::from_byte(t.to_byte()) == Some(*t)));
+    let e = Field::parse(b'x').unwrap_err();
+    assert_eq!(
+        e.to_string(),
+        "expected `.`, `#`, `^`, `>`, `v` or `<` but found `x`"
+    );
+    assert_eq!(Mirror::parse(b'|').unwrap_err().expected, "a mirror");
+    assert_eq!(Mirror::parse(b'\\'), Ok(Mirror::Backslash));
+    assert_eq!(Direction::all(), Direction::ALL);
+}
@@ -0,0 +1,152 @@
+//! `#[derive(Tile)]` for the enums of the tiles of a map, see the `Tile` trait of the `grid`
+//! crate.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitByte, LitStr, Type};
+
+/// Derives `grid::Tile` for an enum.
+///
+/// Unit variants name the byte they are parsed from and rendered as with `#[tile(b'#')]`. A
+/// variant holding a single field stands for all tiles of the field's type, which has to be a
+/// `Tile` itself. `#[tile(expected = "...")]` on the enum describes its tiles in parse errors,
+/// which list their bytes otherwise.
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Tile)]
+/// #[tile(expected = "a path, forest or slope")]
+/// enum Field {
+///     #[tile(b'.')]
+///     Path,
+///     #[tile(b'#')]
+///     Forest,
+///     Slope(Direction),
+/// }
+/// ```
+#[proc_macro_derive(Tile, attributes(tile))]
+pub fn derive_tile(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tile(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The tiles a variant stands for.
+enum Tiles<'a> {
+    Byte(LitByte),
+    Nested(&'a Type),
+}
+
+fn tile(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "Only enums can be derived as tiles",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "Tiles can not be generic",
+        ));
+    }
+    let mut expected: Option<LitStr> = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("tile")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("expected") {
+                expected = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `expected = \"...\"`"))
+            }
+        })?;
+    }
+
+    let mut variants: Vec<(&Ident, Tiles)> = Vec::new();
+    for variant in &data.variants {
+        let mut byte: Option<LitByte> = None;
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("tile")) {
+            byte = Some(attr.parse_args()?);
+        }
+        let tiles = match (&variant.fields, byte) {
+            (Fields::Unit, Some(byte)) => {
+                let used = variants.iter().find_map(|(other, tiles)| match tiles {
+                    Tiles::Byte(b) if b.value() == byte.value() => Some(other),
+                    _ => None,
+                });
+                if let Some(other) = used {
+                    let message = format!("The byte is already the one of `{other}`");
+                    return Err(Error::new_spanned(byte, message));
+                }
+                Tiles::Byte(byte)
+            }
+            (Fields::Unnamed(fields), None) if fields.unnamed.len() == 1 => {
+                Tiles::Nested(&fields.unnamed[0].ty)
+            }
+            (Fields::Unit, None) => {
+                let message = "Missing the byte of the tile like `#[tile(b'.')]`";
+                return Err(Error::new_spanned(variant, message));
+            }
+            _ => {
+                let message = "Tiles are unit variants with a byte or hold a single tile";
+                return Err(Error::new_spanned(variant, message));
+            }
+        };
+        variants.push((&variant.ident, tiles));
+    }
+
+    let mut from_byte = Vec::new();
+    let mut nested = Vec::new();
+    let mut to_byte = Vec::new();
+    let mut all = Vec::new();
+    for (ident, tiles) in &variants {
+        match tiles {
+            Tiles::Byte(byte) => {
+                from_byte.push(quote!(#byte => ::core::option::Option::Some(Self::#ident),));
+                to_byte.push(quote!(Self::#ident => #byte,));
+                all.push(quote!(all.push(Self::#ident);));
+            }
+            Tiles::Nested(ty) => {
+                nested.push(
+                    quote!(.or_else(|| <#ty as ::grid::Tile>::from_byte(byte).map(Self::#ident))),
+                );
+                to_byte.push(quote!(Self::#ident(tile) => ::grid::Tile::to_byte(tile),));
+                all.push(
+                    quote!(all.extend(<#ty as ::grid::Tile>::all().into_iter().map(Self::#ident));),
+                );
+            }
+        }
+    }
+    let expected = expected.map(|e| {
+        quote! {
+            fn expected() -> ::std::string::String {
+                ::std::string::String::from(#e)
+            }
+        }
+    });
+    let name = &input.ident;
+    Ok(quote! {
+        impl ::grid::Tile for #name {
+            fn from_byte(byte: u8) -> ::core::option::Option<Self> {
+                match byte {
+                    #(#from_byte)*
+                    _ => ::core::option::Option::None #(#nested)*,
+                }
+            }
+
+            fn to_byte(self) -> u8 {
+                match self {
+                    #(#to_byte)*
+                }
+            }
+
+            fn all() -> ::std::vec::Vec<Self> {
+                let mut all = ::std::vec::Vec::new();
+                #(#all)*
+                all
+            }
+
+            #expected
+        }
+    })
+}